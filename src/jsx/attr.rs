@@ -1,5 +1,8 @@
 use swc_core::{
-    atoms::wtf8::{Wtf8, Wtf8Buf},
+    atoms::{
+        Atom,
+        wtf8::{Wtf8, Wtf8Buf},
+    },
     common::{DUMMY_SP, Span},
     ecma::ast::*,
 };
@@ -43,6 +46,91 @@ pub(super) fn jsx_attr_value_to_expr_or_invalid(v: JSXAttrValue, err_span: Span)
     })
 }
 
+/// Returns the key of an object literal property when it is known at compile
+/// time, e.g. `{ id: 1, title, "data-x": 2 }`.
+pub(super) fn static_prop_key(prop: &PropOrSpread) -> Option<Atom> {
+    let PropOrSpread::Prop(prop) = prop else {
+        return None;
+    };
+
+    let key = match &**prop {
+        Prop::Shorthand(ident) => return Some(ident.sym.clone()),
+        Prop::KeyValue(KeyValueProp { key, .. }) | Prop::Method(MethodProp { key, .. }) => key,
+        _ => return None,
+    };
+
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.clone()),
+        PropName::Str(s) => s.value.as_str().map(Atom::from),
+        PropName::Num(n) => Some(n.value.to_string().into()),
+        _ => None,
+    }
+}
+
+/// Whether the literal has a `__proto__: value` property, which sets its
+/// prototype rather than defining a property that a spread would copy
+pub(super) fn sets_prototype(obj: &ObjectLit) -> bool {
+    obj.props.iter().any(|prop| {
+        let PropOrSpread::Prop(prop) = prop else {
+            return false;
+        };
+
+        match &**prop {
+            Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(ident),
+                ..
+            }) => ident.sym == "__proto__",
+            Prop::KeyValue(KeyValueProp {
+                key: PropName::Str(s),
+                ..
+            }) => s.value == "__proto__",
+            _ => false,
+        }
+    })
+}
+
+/// Unwraps the value of a `key: value` or shorthand property, other kinds of
+/// properties are given back as-is.
+pub(super) fn prop_value(prop: PropOrSpread) -> Result<Box<Expr>, PropOrSpread> {
+    match prop {
+        PropOrSpread::Prop(prop) => match *prop {
+            Prop::KeyValue(KeyValueProp { value, .. }) => Ok(value),
+            Prop::Shorthand(ident) => Ok(Box::new(Expr::Ident(ident))),
+            prop => Err(PropOrSpread::Prop(Box::new(prop))),
+        },
+        prop => Err(prop),
+    }
+}
+
+/// Removes a valueless marker attribute such as `$Tag`, returning its span if
+/// it was present
+pub(super) fn take_marker_attr(attrs: &mut Vec<JSXAttrOrSpread>, name: &str) -> Option<Span> {
+    let mut marker = None;
+    attrs.retain(|attr| match attr {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            span,
+            name: JSXAttrName::Ident(ident),
+            value: None,
+        }) if ident.sym == *name => {
+            marker = Some(*span);
+            false
+        }
+        _ => true,
+    });
+
+    marker
+}
+
+/// Props which `normalizeProps` moves out of the props object at runtime,
+/// components only have their `key` and `ref` moved.
+pub(super) fn is_normalized_prop(key: &str, is_element: bool) -> bool {
+    match key {
+        "key" | "ref" => true,
+        "className" | "class" | "children" => is_element,
+        _ => false,
+    }
+}
+
 fn transform_jsx_attr_str(v: &Wtf8) -> Wtf8Buf {
     let mut buf = Wtf8Buf::with_capacity(v.len());
     let mut iter = v.code_points().peekable();
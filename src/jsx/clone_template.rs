@@ -0,0 +1,644 @@
+use rustc_hash::FxHasher;
+use std::hash::{Hash, Hasher};
+use swc_core::common::DUMMY_SP;
+use swc_core::common::comments::Comments;
+use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::{ExprFactory, private_ident};
+
+use super::attr::jsx_attr_value_to_expr;
+use super::inline_helpers::parse_inline_helper;
+use super::ssr::{VOID_ELEMENTS, escape_html, is_event};
+use super::text::{jsx_text_to_str, needs_leading_newline, preserve_element_whitespace};
+use super::types::ChildShape;
+use super::{Jsx, is_component_vnode, is_empty_child};
+use crate::transformations::lowercase_attrs::requires_lowercasing;
+use crate::transformations::transform_attribute::transform_attribute;
+
+/// Subtrees with fewer elements are cheaper to create as vNodes
+const MIN_TEMPLATE_ELEMENTS: usize = 3;
+
+/// Elements whose content is not parsed as plain HTML or which have state
+/// that only props can set
+const NON_TEMPLATE_TAGS: [&str; 12] = [
+    "svg", "math", "script", "style", "template", "textarea", "select", "option", "iframe",
+    "noscript", "title", "object",
+];
+
+/// Elements which end an open `<p>` when parsed
+const BLOCK_TAGS: [&str; 40] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "center",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "li",
+    "listing",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "plaintext",
+    "pre",
+    "search",
+    "section",
+    "summary",
+    "table",
+    "ul",
+];
+
+const HEADING_TAGS: [&str; 6] = ["h1", "h2", "h3", "h4", "h5", "h6"];
+
+/// Elements which hide the open elements outside of them from the parser,
+/// like a `<p>` around a `<table>`
+const SCOPE_TAGS: [&str; 9] = [
+    "applet", "caption", "html", "marquee", "object", "table", "td", "template", "th",
+];
+
+/// Functions injected into modules compiled with `templateCloning`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TemplateHelper {
+    Create,
+    Clone,
+}
+
+impl TemplateHelper {
+    pub(super) const ALL: [TemplateHelper; 2] = [TemplateHelper::Create, TemplateHelper::Clone];
+
+    fn name(self) -> &'static str {
+        match self {
+            TemplateHelper::Create => "createTemplate",
+            TemplateHelper::Clone => "cloneTemplate",
+        }
+    }
+
+    /// Each slot is the path of child indexes to its node followed by the
+    /// attribute it sets, or `null` for the text content
+    fn source(self) -> &'static str {
+        match self {
+            TemplateHelper::Create => {
+                r#"function createTemplate(html, slots) {
+                    return { html: html, slots: slots, content: null };
+                }"#
+            }
+            // Used as the `ref` of the root element, which Inferno calls with
+            // the element on mount and again whenever the ref changes
+            TemplateHelper::Clone => {
+                r#"function cloneTemplate(template, values) {
+                    return function (dom) {
+                        if (dom == null) return;
+                        var last = dom.$tv;
+                        if (dom.$t !== template) {
+                            if (template.content == null) {
+                                var el = document.createElement("template");
+                                el.innerHTML = template.html;
+                                template.content = el.content;
+                            }
+                            dom.textContent = "";
+                            dom.appendChild(template.content.cloneNode(true));
+                            var nodes = [];
+                            for (var i = 0; i < template.slots.length; i++) {
+                                var node = dom;
+                                for (var j = 0; j < template.slots[i].length - 1; j++) {
+                                    node = node.childNodes[template.slots[i][j]];
+                                }
+                                nodes.push(node);
+                            }
+                            dom.$t = template;
+                            dom.$tn = nodes;
+                            last = null;
+                        }
+                        for (var k = 0; k < values.length; k++) {
+                            var value = values[k];
+                            if (last && last[k] === value) continue;
+                            var slot = template.slots[k];
+                            var name = slot[slot.length - 1];
+                            if (name === null) {
+                                dom.$tn[k].textContent = value == null || typeof value === "boolean" ? "" : value;
+                            } else if (value == null || value === false) {
+                                dom.$tn[k].removeAttribute(name);
+                            } else {
+                                dom.$tn[k].setAttribute(name, value === true ? "" : value);
+                            }
+                        }
+                        dom.$tv = values;
+                    };
+                }"#
+            }
+        }
+    }
+}
+
+/// Static HTML of the children of an element and its dynamic holes
+#[derive(Default, Hash)]
+struct TemplateBuilder {
+    html: String,
+    /// Paths of the nodes and the attribute names, `None` for text content
+    slots: Vec<(Vec<usize>, Option<String>)>,
+    values: Vec<Expr>,
+    elements: usize,
+}
+
+impl<C> Jsx<C>
+where
+    C: Comments,
+{
+    /// Replaces the children of a host element with a template which is
+    /// cloned by its `ref`, when every descendant is a static element and the
+    /// only dynamic parts are attribute values and text
+    ///
+    /// `<ul><li>{a}</li><li>b</li><li>c</li></ul>` =>
+    /// `<ul ref={cloneTemplate(template, [a])} />`
+    pub(super) fn clone_template(&mut self, el: &mut JSXElement) {
+        if !self.template_cloning {
+            return;
+        }
+
+        let Some(template) = self.template_of(el) else {
+            return;
+        };
+
+        if template.elements < MIN_TEMPLATE_ELEMENTS {
+            return;
+        }
+
+        // Inferno replaces elements whose key changed instead of patching their
+        // children, which would mix them with the cloned nodes
+        let mut hasher = FxHasher::default();
+        template.html.hash(&mut hasher);
+        template.slots.hash(&mut hasher);
+        let template_key = format!("$t{:x}", hasher.finish() as u32);
+        self.key_by_template(el, template_key);
+
+        let local = private_ident!("template");
+        let slots = ArrayLit {
+            span: DUMMY_SP,
+            elems: template
+                .slots
+                .into_iter()
+                .map(|(path, attr)| {
+                    let mut elems: Vec<_> = path
+                        .into_iter()
+                        .map(|index| Some((index as f64).as_arg()))
+                        .collect();
+                    elems.push(Some(match attr {
+                        Some(attr) => attr.as_arg(),
+                        None => Lit::Null(Null { span: DUMMY_SP }).as_arg(),
+                    }));
+
+                    Some(
+                        ArrayLit {
+                            span: DUMMY_SP,
+                            elems,
+                        }
+                        .as_arg(),
+                    )
+                })
+                .collect(),
+        };
+
+        let create = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: self.template_helper(TemplateHelper::Create).as_callee(),
+            args: vec![template.html.as_arg(), slots.as_arg()],
+            ..Default::default()
+        });
+        self.templates.push((local.clone(), create));
+
+        let values = ArrayLit {
+            span: DUMMY_SP,
+            elems: template
+                .values
+                .into_iter()
+                .map(|value| Some(value.as_arg()))
+                .collect(),
+        };
+        let clone = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: self.template_helper(TemplateHelper::Clone).as_callee(),
+            args: vec![local.as_arg(), values.as_arg()],
+            ..Default::default()
+        });
+
+        el.children.clear();
+        el.opening.attrs.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+            span: DUMMY_SP,
+            name: JSXAttrName::Ident(IdentName::new("ref".into(), DUMMY_SP)),
+            value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                span: DUMMY_SP,
+                expr: JSXExpr::Expr(Box::new(clone)),
+            })),
+        }));
+    }
+
+    /// `<ul>` => `<ul key="$t1a2b">`, `<ul key={id}>` => `<ul key={id + "$t1a2b"}>`
+    fn key_by_template(&self, el: &mut JSXElement, template_key: String) {
+        let key = el.opening.attrs.iter_mut().find_map(|attr| match attr {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                value,
+                ..
+            }) if name.sym == "key" => Some(value),
+            _ => None,
+        });
+
+        match key {
+            Some(value) => {
+                let Some(key) = value.take().and_then(jsx_attr_value_to_expr) else {
+                    return;
+                };
+                *value = Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    span: DUMMY_SP,
+                    expr: JSXExpr::Expr(Box::new(Expr::Bin(BinExpr {
+                        span: DUMMY_SP,
+                        op: BinaryOp::Add,
+                        left: key,
+                        right: template_key.into(),
+                    }))),
+                }));
+            }
+            None => el.opening.attrs.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+                span: DUMMY_SP,
+                name: JSXAttrName::Ident(IdentName::new("key".into(), DUMMY_SP)),
+                value: Some(JSXAttrValue::Str(Str {
+                    span: DUMMY_SP,
+                    raw: None,
+                    value: template_key.into(),
+                })),
+            })),
+        }
+    }
+
+    fn template_of(&self, el: &JSXElement) -> Option<TemplateBuilder> {
+        let tag = host_tag(&el.opening.name)?;
+        if NON_TEMPLATE_TAGS.contains(&tag) || VOID_ELEMENTS.contains(&tag) {
+            return None;
+        }
+
+        // The root keeps its props, only the ones which conflict with the
+        // template are not allowed
+        for attr in &el.opening.attrs {
+            match attr {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    ..
+                }) if !matches!(&*name.sym, "ref" | "children" | "dangerouslySetInnerHTML")
+                    && !name.sym.starts_with('$') => {}
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::JSXNamespacedName(..),
+                    ..
+                }) => {}
+                _ => return None,
+            }
+        }
+
+        let mut template = TemplateBuilder::default();
+        self.template_children(
+            &el.children,
+            &mut vec![tag.to_string()],
+            &mut vec![],
+            &mut template,
+        )?;
+
+        Some(template)
+    }
+
+    fn template_children(
+        &self,
+        children: &[JSXElementChild],
+        ancestors: &mut Vec<String>,
+        path: &mut Vec<usize>,
+        template: &mut TemplateBuilder,
+    ) -> Option<()> {
+        let significant: Vec<_> = children
+            .iter()
+            .filter(|child| !is_empty_child(child))
+            .collect();
+
+        // A text hole is only possible when it is the only child
+        if let [
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }),
+        ] = &significant[..]
+            && static_text(expr).is_none()
+        {
+            if !self.is_text_expr(expr) {
+                return None;
+            }
+
+            template.slots.push((path.clone(), None));
+            template.values.push((**expr).clone());
+            return Some(());
+        }
+
+        let mut nodes = 0;
+        let mut in_text = false;
+
+        for child in significant {
+            let text = match child {
+                JSXElementChild::JSXText(text) => {
+                    jsx_text_to_str(&*text.value).to_string_lossy().into_owned()
+                }
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) => static_text(expr)?,
+                JSXElementChild::JSXElement(el) => {
+                    // Adjacent text is parsed as a single node
+                    in_text = false;
+                    path.push(nodes);
+                    self.template_element(el, ancestors, path, template)?;
+                    path.pop();
+                    nodes += 1;
+                    continue;
+                }
+                _ => return None,
+            };
+
+            // The HTML parser does not create empty text nodes
+            if text.is_empty() {
+                continue;
+            }
+
+            if !in_text {
+                in_text = true;
+                nodes += 1;
+            }
+            template.html.push_str(&escape_html(&text));
+        }
+
+        Some(())
+    }
+
+    fn template_element(
+        &self,
+        el: &JSXElement,
+        ancestors: &mut Vec<String>,
+        path: &mut Vec<usize>,
+        template: &mut TemplateBuilder,
+    ) -> Option<()> {
+        let tag = host_tag(&el.opening.name)?;
+        if NON_TEMPLATE_TAGS.contains(&tag) || is_restructured(ancestors, tag) {
+            return None;
+        }
+
+        template.html.push('<');
+        template.html.push_str(tag);
+
+        for attr in &el.opening.attrs {
+            let JSXAttrOrSpread::JSXAttr(attr) = attr else {
+                return None;
+            };
+
+            let name = match &attr.name {
+                JSXAttrName::Ident(name) => {
+                    let name = &*name.sym;
+                    if name.starts_with('$')
+                        || is_event(name)
+                        || matches!(
+                            name,
+                            "key"
+                                | "ref"
+                                | "children"
+                                | "dangerouslySetInnerHTML"
+                                | "value"
+                                | "checked"
+                                | "selected"
+                                | "defaultValue"
+                                | "defaultChecked"
+                        )
+                    {
+                        return None;
+                    }
+
+                    match name {
+                        "className" => "class".to_string(),
+                        name if requires_lowercasing(name) => name.to_lowercase(),
+                        name => transform_attribute(name).to_string(),
+                    }
+                }
+                JSXAttrName::JSXNamespacedName(JSXNamespacedName { ns, name, .. }) => {
+                    format!("{}:{}", ns.sym, name.sym)
+                }
+            };
+
+            let Some(value) = &attr.value else {
+                template.html.push_str(&format!(" {name}"));
+                continue;
+            };
+
+            match jsx_attr_value_to_expr(value.clone()).map(|value| *value) {
+                None | Some(Expr::Lit(Lit::Bool(Bool { value: false, .. }) | Lit::Null(..))) => {}
+                Some(Expr::Lit(Lit::Bool(..))) => template.html.push_str(&format!(" {name}")),
+                Some(Expr::Lit(Lit::Str(s))) => template.html.push_str(&format!(
+                    " {name}=\"{}\"",
+                    escape_html(&s.value.to_string_lossy())
+                )),
+                Some(Expr::Lit(Lit::Num(n))) => {
+                    template.html.push_str(&format!(" {name}=\"{}\"", n.value))
+                }
+                Some(Expr::JSXElement(..) | Expr::JSXFragment(..)) => return None,
+                // Style objects are serialized by Inferno
+                Some(..) if name == "style" => return None,
+                Some(value) => {
+                    template.slots.push((path.clone(), Some(name)));
+                    template.values.push(value);
+                }
+            }
+        }
+
+        template.html.push('>');
+        template.elements += 1;
+
+        if VOID_ELEMENTS.contains(&tag) {
+            return el.children.iter().all(is_empty_child).then_some(());
+        }
+
+        // Nested elements do not pass through `jsx_elem_to_expr`, which
+        // preserves the whitespace of the root
+        let preserved;
+        let children = if tag == "pre" {
+            let mut pre = el.clone();
+            preserve_element_whitespace(&mut pre);
+            preserved = pre.children;
+            &preserved
+        } else {
+            &el.children
+        };
+        if needs_leading_newline(tag, children) {
+            template.html.push('\n');
+        }
+        ancestors.push(tag.to_string());
+        self.template_children(children, ancestors, path, template)?;
+        ancestors.pop();
+        template.html.push_str(&format!("</{tag}>"));
+
+        Some(())
+    }
+
+    /// Text holes are set as text content, so the value must never be a vNode
+    pub(super) fn is_text_expr(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Lit(Lit::Str(..) | Lit::Num(..)) | Expr::Tpl(..) => true,
+            Expr::Paren(ParenExpr { expr, .. }) => self.is_text_expr(expr),
+            Expr::Bin(BinExpr {
+                op: BinaryOp::Add,
+                left,
+                right,
+                ..
+            }) => {
+                matches!(&**left, Expr::Lit(Lit::Str(..)) | Expr::Tpl(..))
+                    || matches!(&**right, Expr::Lit(Lit::Str(..)) | Expr::Tpl(..))
+            }
+            _ => self.expr_child_shape(expr) == Some(ChildShape::Text),
+        }
+    }
+
+    fn template_helper(&mut self, helper: TemplateHelper) -> Ident {
+        self.template_helpers[helper as usize]
+            .get_or_insert_with(|| private_ident!(helper.name()))
+            .clone()
+    }
+
+    /// Declarations of the helpers and the templates used by the module
+    pub(super) fn take_templates(&mut self) -> Vec<Stmt> {
+        let mut locals = vec![];
+        for helper in TemplateHelper::ALL {
+            if let Some(local) = &self.template_helpers[helper as usize] {
+                locals.push((helper.name(), local.clone()));
+            }
+        }
+
+        let mut stmts = vec![];
+        for helper in TemplateHelper::ALL {
+            if self.template_helpers[helper as usize].take().is_some() {
+                stmts.extend(parse_inline_helper(helper.source(), &locals));
+            }
+        }
+
+        for (local, create) in self.templates.drain(..) {
+            stmts.push(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: local.into(),
+                    init: Some(Box::new(create)),
+                    definite: false,
+                }],
+                ..Default::default()
+            }))));
+        }
+
+        stmts
+    }
+}
+
+fn host_tag(name: &JSXElementName) -> Option<&str> {
+    match name {
+        JSXElementName::Ident(ident) if ident.sym != "this" && !is_component_vnode(ident) => {
+            Some(&ident.sym)
+        }
+        _ => None,
+    }
+}
+
+fn static_text(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string_lossy().into_owned()),
+        Expr::Lit(Lit::Num(n)) => Some(n.value.to_string()),
+        _ => None,
+    }
+}
+
+/// Nesting which the HTML parser would change, like a `<tr>` outside of a
+/// `<tbody>` or a `<div>` within a `<p>`, so the paths of the slots would not
+/// match. `ancestors` are the open elements, the parent last.
+fn is_restructured(ancestors: &[String], tag: &str) -> bool {
+    let parent = ancestors.last().map_or("", |parent| parent.as_str());
+    match tag {
+        "tr" => return !matches!(parent, "tbody" | "thead" | "tfoot"),
+        "td" | "th" => return parent != "tr",
+        "tbody" | "thead" | "tfoot" | "caption" | "colgroup" => return parent != "table",
+        "col" => return parent != "colgroup",
+        "rb" | "rtc" => return parent != "ruby",
+        "rt" | "rp" => return !matches!(parent, "ruby" | "rtc"),
+        // Dropped or renamed by the parser
+        "html" | "head" | "body" | "frameset" | "frame" | "image" => return true,
+        _ => {}
+    }
+
+    if matches!(
+        parent,
+        "table" | "tbody" | "thead" | "tfoot" | "tr" | "colgroup"
+    ) {
+        return true;
+    }
+    if BLOCK_TAGS.contains(&tag) && in_scope(ancestors, "p", true) {
+        return true;
+    }
+    if HEADING_TAGS.contains(&tag) && HEADING_TAGS.contains(&parent) {
+        return true;
+    }
+
+    match tag {
+        "a" | "nobr" | "button" => in_scope(ancestors, tag, false),
+        "form" => ancestors.iter().any(|open| open == "form"),
+        "li" => closes_list_item(ancestors, &["li"]),
+        "dd" | "dt" => closes_list_item(ancestors, &["dd", "dt"]),
+        _ => false,
+    }
+}
+
+/// Whether an open `tag` is visible to the parser, which closes it before
+/// inserting an element of the same kind
+fn in_scope(ancestors: &[String], tag: &str, button_scope: bool) -> bool {
+    for open in ancestors.iter().rev() {
+        if open == tag {
+            return true;
+        }
+        if SCOPE_TAGS.contains(&open.as_str()) || (button_scope && open == "button") {
+            return false;
+        }
+    }
+
+    false
+}
+
+/// `<li><span><li>` closes the first `<li>`, a list in between starts a new
+/// one
+fn closes_list_item(ancestors: &[String], items: &[&str]) -> bool {
+    for open in ancestors.iter().rev() {
+        if items.contains(&open.as_str()) {
+            return true;
+        }
+        if matches!(&**open, "ul" | "ol" | "menu" | "dl") || SCOPE_TAGS.contains(&open.as_str()) {
+            return false;
+        }
+    }
+
+    false
+}
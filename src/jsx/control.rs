@@ -0,0 +1,318 @@
+use rustc_hash::FxHashSet;
+use swc_core::atoms::Atom;
+use swc_core::common::comments::Comments;
+use swc_core::common::{DUMMY_SP, Span, Spanned, SyntaxContext};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::{ExprFactory, collect_decls};
+use swc_core::ecma::visit::{Visit, VisitWith, noop_visit_type};
+use swc_core::plugin::errors::HANDLER;
+
+use super::attr::jsx_attr_value_to_expr;
+use super::{Jsx, is_empty_child};
+
+/// Elements compiled to plain expressions when `controlStatements` is enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ControlStatement {
+    /// `<If condition={c}>..</If>` => `c ? .. : null`
+    If,
+    /// `<Choose><When condition={c}>..</When><Otherwise>..</Otherwise></Choose>`
+    Choose,
+    /// `<For each="item" index="i" of={list}>..</For>` => `list.map((item, i) => ..)`
+    For,
+    /// Only valid as direct children of `<Choose>`
+    When,
+    Otherwise,
+}
+
+impl<C> Jsx<C>
+where
+    C: Comments,
+{
+    /// Control statements are only recognized when the name is not bound, so a
+    /// component called `If` keeps working
+    pub(super) fn control_statement(&self, name: &JSXElementName) -> Option<ControlStatement> {
+        if !self.control_statements {
+            return None;
+        }
+
+        let JSXElementName::Ident(ident) = name else {
+            return None;
+        };
+
+        if !self.is_unresolved(ident) {
+            return None;
+        }
+
+        match &*ident.sym {
+            "If" => Some(ControlStatement::If),
+            "Choose" => Some(ControlStatement::Choose),
+            "For" => Some(ControlStatement::For),
+            "When" => Some(ControlStatement::When),
+            "Otherwise" => Some(ControlStatement::Otherwise),
+            _ => None,
+        }
+    }
+
+    /// Whether a control statement has nothing to render, an empty `<For>` is
+    /// compiled to `null` rather than an array of `null`s
+    pub(super) fn has_empty_body(&self, children: &[JSXElementChild]) -> bool {
+        self.flatten_fragments(children.to_vec())
+            .iter()
+            .all(is_empty_child)
+    }
+
+    /// Whether the body of a `<For>` is a single element with a `key`, so the
+    /// resulting array can be passed as `HasKeyedChildren`. `None` when the
+    /// body may render `null`, text or several nodes, which the array of
+    /// children can not contain without being normalized.
+    pub(super) fn for_child_keyed(&self, el: &JSXElement) -> Option<bool> {
+        let children = self.flatten_fragments(el.children.clone());
+        let mut significant = children.iter().filter(|child| !is_empty_child(child));
+
+        match (significant.next(), significant.next()) {
+            (Some(JSXElementChild::JSXElement(child)), None)
+                if self.control_statement(&child.opening.name).is_none()
+                    && !self.is_fragment_name(&child.opening.name) =>
+            {
+                Some(Self::does_children_have_key_defined(child))
+            }
+            _ => None,
+        }
+    }
+
+    pub(super) fn jsx_control_to_expr(
+        &mut self,
+        el: JSXElement,
+        control: ControlStatement,
+    ) -> Expr {
+        let span = el.span();
+        let opening_span = el.opening.span;
+
+        match control {
+            ControlStatement::If => {
+                let [condition] = control_attrs(el.opening.attrs, ["condition"]);
+                let Some(condition) = required_attr(condition, "condition", opening_span) else {
+                    return Expr::Invalid(Invalid { span });
+                };
+
+                Expr::Cond(CondExpr {
+                    span,
+                    test: condition,
+                    cons: Box::new(self.control_body(span, el.children)),
+                    alt: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+                })
+            }
+            ControlStatement::Choose => {
+                let mut whens = vec![];
+                let mut otherwise = None;
+
+                for child in el.children {
+                    if is_empty_child(&child) {
+                        continue;
+                    }
+
+                    let JSXElementChild::JSXElement(child) = child else {
+                        control_error(
+                            child.span(),
+                            "<Choose> may only contain <When> and <Otherwise>",
+                        );
+                        continue;
+                    };
+                    let child = *child;
+
+                    match self.control_statement(&child.opening.name) {
+                        Some(ControlStatement::When) if otherwise.is_none() => {
+                            let [condition] = control_attrs(child.opening.attrs, ["condition"]);
+                            if let Some(condition) =
+                                required_attr(condition, "condition", child.opening.span)
+                            {
+                                whens.push((
+                                    condition,
+                                    self.control_body(child.span, child.children),
+                                ));
+                            }
+                        }
+                        Some(ControlStatement::Otherwise) if otherwise.is_none() => {
+                            otherwise = Some(self.control_body(child.span, child.children));
+                        }
+                        Some(ControlStatement::When | ControlStatement::Otherwise) => {
+                            control_error(
+                                child.span,
+                                "<Otherwise> must be the last child of <Choose>",
+                            );
+                        }
+                        _ => {
+                            control_error(
+                                child.span,
+                                "<Choose> may only contain <When> and <Otherwise>",
+                            );
+                        }
+                    }
+                }
+
+                whens.into_iter().rev().fold(
+                    otherwise.unwrap_or(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+                    |alt, (test, cons)| {
+                        Expr::Cond(CondExpr {
+                            span: DUMMY_SP,
+                            test,
+                            cons: Box::new(cons),
+                            alt: Box::new(alt),
+                        })
+                    },
+                )
+            }
+            ControlStatement::For => {
+                let [each, index, list] = control_attrs(el.opening.attrs, ["each", "index", "of"]);
+                let Some(list) = required_attr(list, "of", opening_span) else {
+                    return Expr::Invalid(Invalid { span });
+                };
+
+                let Some(each) = required_attr(each, "each", opening_span) else {
+                    return Expr::Invalid(Invalid { span });
+                };
+
+                if self.has_empty_body(&el.children) {
+                    return Expr::Lit(Lit::Null(Null { span }));
+                }
+
+                let mut params = vec![];
+                for name in [Some(each), index].into_iter().flatten() {
+                    let Some(name) = binding_name(&name) else {
+                        control_error(
+                            name.span(),
+                            "The values of properties 'each' and 'index' should be strings",
+                        );
+                        return Expr::Invalid(Invalid { span });
+                    };
+
+                    params.push(Pat::Ident(self.loop_binding(&el.children, name).into()));
+                }
+
+                // The pure annotation belongs to the vNode call within the
+                // callback rather than to `map`, which runs user code, so the
+                // body takes the span of the closing tag which no other call has
+                let body_span = el.closing.as_ref().map_or(span, |closing| closing.span);
+                let body = self.control_body(body_span, el.children);
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: list
+                        .make_member(IdentName::new("map".into(), DUMMY_SP))
+                        .as_callee(),
+                    args: vec![
+                        ArrowExpr {
+                            span: DUMMY_SP,
+                            params,
+                            body: Box::new(BlockStmtOrExpr::Expr(Box::new(body))),
+                            ..Default::default()
+                        }
+                        .as_arg(),
+                    ],
+                    ..Default::default()
+                })
+            }
+            ControlStatement::When | ControlStatement::Otherwise => {
+                control_error(
+                    span,
+                    "<When> and <Otherwise> must be direct children of <Choose>",
+                );
+
+                Expr::Invalid(Invalid { span })
+            }
+        }
+    }
+
+    /// Children of a control statement, a single element is used as is
+    fn control_body(&mut self, span: Span, children: Vec<JSXElementChild>) -> Expr {
+        self.jsx_frag_to_expr(JSXFragment {
+            span,
+            opening: JSXOpeningFragment { span },
+            children,
+            closing: JSXClosingFragment { span: DUMMY_SP },
+        })
+    }
+
+    /// The loop variable takes the syntax context of its references in the
+    /// body, which the resolver could not bind to anything. Bindings declared
+    /// within the body, like the parameter of a nested `map`, are other
+    /// variables with the same name.
+    fn loop_binding(&self, children: &[JSXElementChild], name: Atom) -> Ident {
+        let inner: FxHashSet<Id> = children.iter().flat_map(collect_decls).collect();
+        let mut finder = IdentFinder {
+            name: &name,
+            inner: &inner,
+            found: None,
+        };
+        children.visit_with(&mut finder);
+
+        match finder.found {
+            Some(ctxt) => Ident::new(name, DUMMY_SP, ctxt),
+            None => Ident::new_no_ctxt(name, DUMMY_SP),
+        }
+    }
+}
+
+fn control_error(span: Span, msg: &str) {
+    HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+}
+
+/// Values of the `names` attributes in the same order, other attributes are
+/// reported as errors
+fn control_attrs<const N: usize>(
+    attrs: Vec<JSXAttrOrSpread>,
+    names: [&str; N],
+) -> [Option<Box<Expr>>; N] {
+    let mut values = [const { None }; N];
+
+    for attr in attrs {
+        if let JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::Ident(name),
+            value,
+            ..
+        }) = &attr
+            && let Some(index) = names.iter().position(|known| name.sym == **known)
+        {
+            values[index] = value.clone().and_then(jsx_attr_value_to_expr);
+            continue;
+        }
+
+        control_error(attr.span(), "Unknown property of a control statement");
+    }
+
+    values
+}
+
+fn required_attr(value: Option<Box<Expr>>, name: &str, span: Span) -> Option<Box<Expr>> {
+    if value.is_none() {
+        control_error(
+            span,
+            &format!("The value of property '{name}' should not be empty"),
+        );
+    }
+
+    value
+}
+
+fn binding_name(value: &Expr) -> Option<Atom> {
+    match value {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_atom_lossy().into_owned()),
+        _ => None,
+    }
+}
+
+struct IdentFinder<'a> {
+    name: &'a Atom,
+    inner: &'a FxHashSet<Id>,
+    found: Option<SyntaxContext>,
+}
+
+impl Visit for IdentFinder<'_> {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if self.found.is_none() && ident.sym == *self.name && !self.inner.contains(&ident.to_id()) {
+            self.found = Some(ident.ctxt);
+        }
+    }
+}
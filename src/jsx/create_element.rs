@@ -0,0 +1,304 @@
+use rustc_hash::FxHashSet;
+use swc_core::common::comments::Comments;
+use swc_core::common::{DUMMY_SP, Span, Spanned};
+use swc_core::ecma::ast::*;
+
+use super::Jsx;
+use super::text::jsx_text_to_str;
+use crate::transformations::lowercase_attrs::requires_lowercasing;
+use crate::transformations::transform_attribute::transform_attribute;
+
+pub(super) const CREATE_ELEMENT_SOURCE: &str = "inferno-create-element";
+
+impl<C> Jsx<C>
+where
+    C: Comments,
+{
+    /// Converts `createElement` and hyperscript calls, when enabled, into the
+    /// equivalent JSX so they share the lowering of elements
+    pub(super) fn call_to_jsx(&mut self, call: &CallExpr) -> Option<JSXElement> {
+        if self.compile_create_element
+            && is_imported_callee(
+                &call.callee,
+                &self.create_element_refs,
+                &self.create_element_namespace_refs,
+                "createElement",
+            )
+        {
+            let el = self.create_element_to_jsx(call)?;
+            self.create_element_lowered = true;
+            return Some(el);
+        }
+
+        if self.compile_hyperscript
+            && is_imported_callee(
+                &call.callee,
+                &self.hyperscript_refs,
+                &self.hyperscript_namespace_refs,
+                "h",
+            )
+        {
+            let el = self.hyperscript_to_jsx(call)?;
+            self.hyperscript_lowered = true;
+            return Some(el);
+        }
+
+        None
+    }
+
+    /// `createElement("div", { className: "a" }, child)` => `<div className="a">{child}</div>`
+    ///
+    /// Only calls which produce the same vNode as the equivalent JSX are
+    /// converted, anything else is left to the runtime `createElement`.
+    fn create_element_to_jsx(&mut self, call: &CallExpr) -> Option<JSXElement> {
+        let mut args = call.args.iter();
+        let tag = args.next()?;
+        if tag.spread.is_some() {
+            return None;
+        }
+
+        let (name, is_element) = match &*tag.expr {
+            Expr::Lit(Lit::Str(s)) => {
+                let tag = s.value.as_str()?;
+                if !tag.starts_with(|c: char| c.is_ascii_lowercase()) || !is_jsx_name(tag) {
+                    return None;
+                }
+
+                (
+                    JSXElementName::Ident(Ident::new_no_ctxt(tag.into(), s.span)),
+                    true,
+                )
+            }
+            tag => (component_name(tag)?, false),
+        };
+
+        let mut attrs = vec![];
+        if let Some(props) = args.next() {
+            if props.spread.is_some() {
+                return None;
+            }
+
+            self.props_to_attrs(&props.expr, is_element, &mut attrs)?;
+        }
+
+        let mut children = vec![];
+        for child in args {
+            if child.spread.is_some() {
+                return None;
+            }
+
+            children.push(self.call_child(&child.expr));
+        }
+
+        Some(jsx_element(call.span, name, attrs, children))
+    }
+
+    /// `null`, an object literal or any other expression, which is spread
+    pub(super) fn props_to_attrs(
+        &self,
+        props: &Expr,
+        is_element: bool,
+        attrs: &mut Vec<JSXAttrOrSpread>,
+    ) -> Option<()> {
+        match props {
+            Expr::Lit(Lit::Null(..)) => {}
+            Expr::Ident(ident) if ident.sym == "undefined" && self.is_unresolved(ident) => {}
+            Expr::Object(obj) => {
+                for prop in &obj.props {
+                    attrs.push(object_prop_to_attr(prop, is_element)?);
+                }
+            }
+            expr => attrs.push(JSXAttrOrSpread::SpreadElement(SpreadElement {
+                dot3_token: DUMMY_SP,
+                expr: Box::new(expr.clone()),
+            })),
+        }
+
+        Some(())
+    }
+
+    /// Nested calls become elements, so the parent gets exact child flags
+    pub(super) fn call_child(&mut self, expr: &Expr) -> JSXElementChild {
+        match expr {
+            Expr::Call(call) => {
+                if let Some(el) = self.call_to_jsx(call) {
+                    return JSXElementChild::JSXElement(Box::new(el));
+                }
+            }
+            // Strings which JSX text would produce unchanged
+            Expr::Lit(Lit::Str(s))
+                if !s.value.is_empty()
+                    && !s
+                        .value
+                        .to_string_lossy()
+                        .contains(['&', '{', '}', '<', '>'])
+                    && jsx_text_to_str(&*s.value) == s.value =>
+            {
+                return JSXElementChild::JSXText(JSXText {
+                    span: s.span,
+                    value: s.value.to_atom_lossy().into_owned(),
+                    raw: s.value.to_atom_lossy().into_owned(),
+                });
+            }
+            _ => {}
+        }
+
+        JSXElementChild::JSXExprContainer(JSXExprContainer {
+            span: expr.span(),
+            expr: JSXExpr::Expr(Box::new(expr.clone())),
+        })
+    }
+}
+
+fn is_imported_callee(
+    callee: &Callee,
+    refs: &FxHashSet<Id>,
+    namespace_refs: &FxHashSet<Id>,
+    name: &str,
+) -> bool {
+    match callee {
+        Callee::Expr(callee) => match &**callee {
+            Expr::Ident(ident) => refs.contains(&ident.to_id()),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => {
+                prop.sym == *name
+                    && obj
+                        .as_ident()
+                        .is_some_and(|obj| namespace_refs.contains(&obj.to_id()))
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+pub(super) fn jsx_element(
+    span: Span,
+    name: JSXElementName,
+    attrs: Vec<JSXAttrOrSpread>,
+    children: Vec<JSXElementChild>,
+) -> JSXElement {
+    let closing = (!children.is_empty()).then(|| JSXClosingElement {
+        span: DUMMY_SP,
+        name: name.clone(),
+    });
+
+    JSXElement {
+        span,
+        opening: JSXOpeningElement {
+            span,
+            name,
+            attrs,
+            self_closing: closing.is_none(),
+            type_args: None,
+        },
+        closing,
+        children,
+    }
+}
+
+/// Components referenced by a capitalized identifier or a member expression
+/// ending in a capitalized property, like `Inferno.Fragment`. Tags held in
+/// lowercase variables or properties, like `props.tag`, may be strings or
+/// components, so they are not converted.
+pub(super) fn component_name(tag: &Expr) -> Option<JSXElementName> {
+    match tag {
+        Expr::Ident(ident) if is_capitalized(&ident.sym) => {
+            Some(JSXElementName::Ident(ident.clone()))
+        }
+        Expr::Member(
+            member @ MemberExpr {
+                prop: MemberProp::Ident(prop),
+                ..
+            },
+        ) if is_capitalized(&prop.sym) => Some(JSXElementName::JSXMemberExpr(
+            create_element_member(member)?,
+        )),
+        _ => None,
+    }
+}
+
+fn is_capitalized(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+fn create_element_member(member: &MemberExpr) -> Option<JSXMemberExpr> {
+    let MemberProp::Ident(prop) = &member.prop else {
+        return None;
+    };
+
+    let obj = match &*member.obj {
+        Expr::Ident(obj) => JSXObject::Ident(obj.clone()),
+        Expr::Member(obj) => JSXObject::JSXMemberExpr(Box::new(create_element_member(obj)?)),
+        _ => return None,
+    };
+
+    Some(JSXMemberExpr {
+        span: member.span,
+        obj,
+        prop: prop.clone(),
+    })
+}
+
+/// Props keep their name, so the ones JSX would rename or treat as compile
+/// time flags are not converted
+pub(super) fn object_prop_to_attr(
+    prop: &PropOrSpread,
+    is_element: bool,
+) -> Option<JSXAttrOrSpread> {
+    let (name, value) = match prop {
+        PropOrSpread::Spread(spread) => {
+            return Some(JSXAttrOrSpread::SpreadElement(spread.clone()));
+        }
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(KeyValueProp { key, value }) => {
+                let name = match key {
+                    PropName::Ident(name) => name.clone(),
+                    PropName::Str(s) => IdentName::new(s.value.as_str()?.into(), s.span),
+                    _ => return None,
+                };
+
+                (name, value.clone())
+            }
+            Prop::Shorthand(ident) => (ident.clone().into(), Box::new(Expr::Ident(ident.clone()))),
+            _ => return None,
+        },
+        #[cfg(swc_ast_unknown)]
+        _ => return None,
+    };
+
+    // A `children` prop does not take part in child flag inference
+    if !is_jsx_name(&name.sym) || name.sym.starts_with('$') || name.sym == "children" {
+        return None;
+    }
+
+    if is_element
+        && (name.sym == "onDoubleClick"
+            || requires_lowercasing(&name.sym)
+            || transform_attribute(&name.sym) != &*name.sym)
+    {
+        return None;
+    }
+
+    Some(JSXAttrOrSpread::JSXAttr(JSXAttr {
+        span: name.span,
+        name: JSXAttrName::Ident(name),
+        value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(value),
+        })),
+    }))
+}
+
+/// Identifiers which may contain dashes, like `data-id` or `my-element`
+pub(super) fn is_jsx_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '-'))
+}
@@ -0,0 +1,624 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::atoms::Atom;
+use swc_core::common::comments::Comments;
+use swc_core::common::{BytePos, Span};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::visit::{Visit, VisitWith, noop_visit_type};
+
+use super::attr::static_prop_key;
+use super::{Jsx, is_component_vnode, is_empty_child};
+
+/// Static `defaultProps` of a component declared in the same module
+pub(super) struct DefaultProps {
+    /// Defaults with primitive values, which can be copied into the props of
+    /// every element
+    props: Vec<(Atom, Expr)>,
+    /// Elements which render the component once its defaults are set and
+    /// whose props are all known, by their start
+    sites: FxHashSet<BytePos>,
+}
+
+struct Candidate {
+    props: Vec<(Atom, Expr)>,
+    /// Whether every default is in `props`
+    complete: bool,
+    /// Elements at the top level of the module are only merged after this
+    /// position
+    from: BytePos,
+    exported: bool,
+    definition: Definition,
+}
+
+enum Definition {
+    /// `Foo.defaultProps = {..}`, by the span of the statement and of its
+    /// `Foo.defaultProps` target
+    Assign { stmt: Span, target: Span },
+    /// `static defaultProps = {..}`, by the span of the class property
+    Static(Span),
+}
+
+impl<C> Jsx<C>
+where
+    C: Comments,
+{
+    /// Defaults which the element does not pass itself
+    pub(super) fn default_props_of(&self, el: &JSXElement) -> Option<Vec<(Atom, Expr)>> {
+        if self.default_props.is_empty() {
+            return None;
+        }
+
+        let JSXElementName::Ident(name) = &el.opening.name else {
+            return None;
+        };
+        let defaults = self.default_props.get(&name.to_id())?;
+        if !defaults.sites.contains(&el.span.lo) {
+            return None;
+        }
+
+        Some(defaults.props.clone())
+    }
+}
+
+/// Appends the defaults whose keys are missing from the props of a component
+/// element, like `createComponentVNode` would at runtime
+pub(super) fn merge_default_props(props: &mut ObjectLit, defaults: Vec<(Atom, Expr)>) {
+    let mut passed = FxHashSet::default();
+    for prop in &props.props {
+        match static_prop_key(prop) {
+            Some(key) => {
+                passed.insert(key);
+            }
+            None => return,
+        }
+    }
+
+    for (key, value) in defaults {
+        if passed.contains(&key) {
+            continue;
+        }
+
+        props
+            .props
+            .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: prop_name(key),
+                value: Box::new(value),
+            }))));
+    }
+}
+
+/// Static `defaultProps` of the components declared at the top level of the
+/// module. Definitions which every element already merges at compile time, of
+/// components which are only rendered by this module, are removed.
+pub(super) fn collect_default_props(module: &mut Module) -> FxHashMap<Id, DefaultProps> {
+    let mut components = FxHashMap::default();
+    let mut candidates: FxHashMap<Id, Candidate> = FxHashMap::default();
+    let mut invalid = FxHashSet::default();
+
+    for item in &module.body {
+        let (decl, exported) = match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => (decl, false),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => (decl, true),
+            _ => continue,
+        };
+
+        let declared: Vec<(&Ident, Option<&Class>)> = match decl {
+            Decl::Class(ClassDecl { ident, class, .. }) => vec![(ident, Some(class))],
+            Decl::Fn(FnDecl { ident, .. }) => vec![(ident, None)],
+            Decl::Var(var) if var.kind == VarDeclKind::Const => var
+                .decls
+                .iter()
+                .filter_map(|decl| {
+                    let Pat::Ident(name) = &decl.name else {
+                        return None;
+                    };
+
+                    match unwrap_paren(decl.init.as_deref()?) {
+                        Expr::Class(ClassExpr { class, .. }) => Some((&name.id, Some(&**class))),
+                        Expr::Arrow(..) | Expr::Fn(..) => Some((&name.id, None)),
+                        _ => None,
+                    }
+                })
+                .collect(),
+            _ => continue,
+        };
+
+        for (ident, class) in declared {
+            if !is_component_vnode(ident) {
+                continue;
+            }
+
+            components.insert(ident.to_id(), exported);
+
+            let Some(class) = class else {
+                continue;
+            };
+            let mut statics = class.body.iter().filter_map(|member| match member {
+                ClassMember::ClassProp(prop)
+                    if prop.is_static && prop_name_is(&prop.key, "defaultProps") =>
+                {
+                    Some(prop)
+                }
+                _ => None,
+            });
+
+            let Some(prop) = statics.next() else {
+                continue;
+            };
+            let defaults = match prop.value.as_deref().map(unwrap_paren) {
+                Some(Expr::Object(obj)) => static_defaults(obj),
+                _ => None,
+            };
+
+            match defaults {
+                Some((props, complete)) if statics.next().is_none() => {
+                    candidates.insert(
+                        ident.to_id(),
+                        Candidate {
+                            props,
+                            complete,
+                            // Classes can not be used before they are declared
+                            from: BytePos(0),
+                            exported,
+                            definition: Definition::Static(prop.span),
+                        },
+                    );
+                }
+                _ => {
+                    invalid.insert(ident.to_id());
+                }
+            }
+        }
+    }
+
+    for item in &module.body {
+        let ModuleItem::Stmt(Stmt::Expr(ExprStmt { span, expr })) = item else {
+            continue;
+        };
+        let Expr::Assign(AssignExpr {
+            op: AssignOp::Assign,
+            left: AssignTarget::Simple(SimpleAssignTarget::Member(target)),
+            right,
+            ..
+        }) = &**expr
+        else {
+            continue;
+        };
+        let (Expr::Ident(obj), true) = (&*target.obj, member_prop_is(&target.prop, "defaultProps"))
+        else {
+            continue;
+        };
+
+        let id = obj.to_id();
+        let Some(&exported) = components.get(&id) else {
+            continue;
+        };
+
+        let defaults = match unwrap_paren(right) {
+            Expr::Object(obj) => static_defaults(obj),
+            _ => None,
+        };
+
+        match defaults {
+            Some((props, complete)) if !candidates.contains_key(&id) => {
+                candidates.insert(
+                    id,
+                    Candidate {
+                        props,
+                        complete,
+                        from: span.hi,
+                        exported,
+                        definition: Definition::Assign {
+                            stmt: *span,
+                            target: target.span,
+                        },
+                    },
+                );
+            }
+            _ => {
+                invalid.insert(id);
+            }
+        }
+    }
+
+    candidates.retain(|id, candidate| !invalid.contains(id) && !candidate.props.is_empty());
+    if candidates.is_empty() {
+        return FxHashMap::default();
+    }
+
+    let mut finder = DefaultPropsUses {
+        candidates: &candidates,
+        functions: 0,
+        sites: FxHashMap::default(),
+        invalid: FxHashSet::default(),
+        escaped: FxHashSet::default(),
+        unmerged: FxHashSet::default(),
+        unknown_access: false,
+    };
+    module.visit_with(&mut finder);
+
+    let DefaultPropsUses {
+        mut sites,
+        invalid,
+        escaped,
+        unmerged,
+        unknown_access,
+        ..
+    } = finder;
+
+    let mut redundant = FxHashSet::default();
+    let mut default_props = FxHashMap::default();
+    for (id, candidate) in candidates {
+        if invalid.contains(&id) {
+            continue;
+        }
+
+        // The runtime merge is only redundant when no element or other module
+        // can see the component without its defaults
+        if candidate.complete
+            && !candidate.exported
+            && !unknown_access
+            && !escaped.contains(&id)
+            && !unmerged.contains(&id)
+            && candidate.props.iter().all(|(key, _)| is_plain_prop(key))
+        {
+            redundant.insert(match candidate.definition {
+                Definition::Assign { stmt, .. } => stmt,
+                Definition::Static(span) => span,
+            });
+        }
+
+        default_props.insert(
+            id.clone(),
+            DefaultProps {
+                props: candidate.props,
+                sites: sites.remove(&id).unwrap_or_default(),
+            },
+        );
+    }
+
+    if !redundant.is_empty() {
+        remove_definitions(module, &redundant);
+    }
+
+    default_props
+}
+
+fn remove_definitions(module: &mut Module, redundant: &FxHashSet<Span>) {
+    module.body.retain(|item| {
+        !matches!(item, ModuleItem::Stmt(Stmt::Expr(ExprStmt { span, .. })) if redundant.contains(span))
+    });
+
+    for item in &mut module.body {
+        let decl = match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => decl,
+            _ => continue,
+        };
+
+        let classes: Vec<&mut Class> = match decl {
+            Decl::Class(ClassDecl { class, .. }) => vec![&mut **class],
+            Decl::Var(var) => var
+                .decls
+                .iter_mut()
+                .filter_map(
+                    |decl| match decl.init.as_deref_mut().map(unwrap_paren_mut) {
+                        Some(Expr::Class(ClassExpr { class, .. })) => Some(&mut **class),
+                        _ => None,
+                    },
+                )
+                .collect(),
+            _ => continue,
+        };
+
+        for class in classes {
+            class.body.retain(|member| {
+                !matches!(member, ClassMember::ClassProp(prop) if redundant.contains(&prop.span))
+            });
+        }
+    }
+}
+
+/// The defaults of an object literal whose keys are all known, and whether
+/// all of their values are primitives
+fn static_defaults(obj: &ObjectLit) -> Option<(Vec<(Atom, Expr)>, bool)> {
+    let mut props: Vec<(Atom, Expr)> = vec![];
+    let mut complete = true;
+
+    for prop in &obj.props {
+        let key = static_prop_key(prop)?;
+        props.retain(|(other, _)| *other != key);
+
+        let PropOrSpread::Prop(prop) = prop else {
+            return None;
+        };
+        match &**prop {
+            Prop::KeyValue(KeyValueProp { value, .. }) if is_primitive(value) => {
+                props.push((key, (**value).clone()));
+            }
+            Prop::KeyValue(..) | Prop::Shorthand(..) => complete = false,
+            _ => return None,
+        }
+    }
+
+    Some((props, complete))
+}
+
+/// Values which are the same each time they are evaluated, copying an object
+/// would give every element its own instance
+fn is_primitive(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(Lit::Regex(..) | Lit::JSXText(..)) => false,
+        Expr::Lit(..) => true,
+        Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+        Expr::Unary(UnaryExpr {
+            op: UnaryOp::Minus,
+            arg,
+            ..
+        }) => matches!(&**arg, Expr::Lit(Lit::Num(..))),
+        Expr::Paren(ParenExpr { expr, .. }) => is_primitive(expr),
+        _ => false,
+    }
+}
+
+/// Values which are never `undefined`, so the runtime would not replace them
+/// by a default
+fn is_defined(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(..)
+        | Expr::Tpl(..)
+        | Expr::Array(..)
+        | Expr::Object(..)
+        | Expr::Arrow(..)
+        | Expr::Fn(..)
+        | Expr::Class(..)
+        | Expr::JSXElement(..)
+        | Expr::JSXFragment(..) => true,
+        Expr::Unary(UnaryExpr { op, .. }) => *op != UnaryOp::Void,
+        Expr::Paren(ParenExpr { expr, .. }) => is_defined(expr),
+        _ => false,
+    }
+}
+
+/// Props which JSX passes as is, rather than as the `key`, `ref`, hooks or
+/// children of a component
+fn is_plain_prop(key: &str) -> bool {
+    !(matches!(
+        key,
+        "key" | "ref" | "children" | "onDoubleClick" | "onDblClick"
+    ) || key.starts_with('$')
+        || key.starts_with("onComponent")
+        || key.contains(':'))
+}
+
+fn prop_name(key: Atom) -> PropName {
+    if Ident::verify_symbol(&key).is_ok() {
+        PropName::Ident(IdentName::new(key, Default::default()))
+    } else {
+        PropName::Str(Str {
+            span: Default::default(),
+            raw: None,
+            value: key.into(),
+        })
+    }
+}
+
+fn prop_name_is(key: &PropName, name: &str) -> bool {
+    match key {
+        PropName::Ident(ident) => ident.sym == name,
+        PropName::Str(s) => s.value == name,
+        _ => false,
+    }
+}
+
+fn member_prop_is(prop: &MemberProp, name: &str) -> bool {
+    match prop {
+        MemberProp::Ident(ident) => ident.sym == name,
+        MemberProp::Computed(ComputedPropName { expr, .. }) => {
+            matches!(&**expr, Expr::Lit(Lit::Str(s)) if s.value == name)
+        }
+        _ => false,
+    }
+}
+
+fn unwrap_paren(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(ParenExpr { expr, .. }) => unwrap_paren(expr),
+        _ => expr,
+    }
+}
+
+fn unwrap_paren_mut(expr: &mut Expr) -> &mut Expr {
+    match expr {
+        Expr::Paren(ParenExpr { expr, .. }) => unwrap_paren_mut(expr),
+        _ => expr,
+    }
+}
+
+struct DefaultPropsUses<'a> {
+    candidates: &'a FxHashMap<Id, Candidate>,
+    functions: usize,
+    sites: FxHashMap<Id, FxHashSet<BytePos>>,
+    /// Components whose defaults are read, changed or replaced
+    invalid: FxHashSet<Id>,
+    /// Components which are referenced other than by rendering them
+    escaped: FxHashSet<Id>,
+    /// Components with an element which still needs the runtime merge
+    unmerged: FxHashSet<Id>,
+    /// `defaultProps` is accessed on something else than an identifier,
+    /// like `this.defaultProps`
+    unknown_access: bool,
+}
+
+impl DefaultPropsUses<'_> {
+    fn element_is_merged(&self, el: &JSXElement, candidate: &Candidate) -> (bool, bool) {
+        if el.span.lo == BytePos(0) || (self.functions == 0 && el.span.lo < candidate.from) {
+            return (false, false);
+        }
+
+        let mut defined = true;
+        for attr in &el.opening.attrs {
+            let JSXAttrOrSpread::JSXAttr(JSXAttr { name, value, .. }) = attr else {
+                return (false, false);
+            };
+            let JSXAttrName::Ident(name) = name else {
+                continue;
+            };
+            if name.sym == "$Tag" {
+                return (false, false);
+            }
+            if !candidate.props.iter().any(|(key, _)| *key == name.sym) {
+                continue;
+            }
+
+            defined &= match value {
+                None | Some(JSXAttrValue::Str(..)) => true,
+                Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                })) => is_defined(expr),
+                Some(JSXAttrValue::JSXElement(..) | JSXAttrValue::JSXFragment(..)) => true,
+                _ => false,
+            };
+        }
+
+        // Children are never merged when the defaults include them
+        if candidate.props.iter().any(|(key, _)| key == "children")
+            && el.children.iter().any(|child| !is_empty_child(child))
+        {
+            defined = false;
+        }
+
+        (true, defined)
+    }
+
+    fn enter_function(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.functions += 1;
+        visit(self);
+        self.functions -= 1;
+    }
+}
+
+impl Visit for DefaultPropsUses<'_> {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        self.enter_function(|this| arrow.visit_children_with(this));
+    }
+
+    fn visit_class_decl(&mut self, decl: &ClassDecl) {
+        decl.class.visit_with(self);
+    }
+
+    fn visit_constructor(&mut self, constructor: &Constructor) {
+        self.enter_function(|this| constructor.visit_children_with(this));
+    }
+
+    fn visit_fn_decl(&mut self, decl: &FnDecl) {
+        decl.function.visit_with(self);
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.enter_function(|this| function.visit_children_with(this));
+    }
+
+    fn visit_getter_prop(&mut self, prop: &GetterProp) {
+        self.enter_function(|this| prop.visit_children_with(this));
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        let id = ident.to_id();
+        if self.candidates.contains_key(&id) {
+            self.escaped.insert(id);
+        }
+    }
+
+    fn visit_jsx_element(&mut self, el: &JSXElement) {
+        if let JSXElementName::Ident(name) = &el.opening.name
+            && let Some(candidate) = self.candidates.get(&name.to_id())
+        {
+            let id = name.to_id();
+            let (merged, defined) = self.element_is_merged(el, candidate);
+            if merged {
+                self.sites.entry(id.clone()).or_default().insert(el.span.lo);
+            }
+            if !(merged && defined) {
+                self.unmerged.insert(id);
+            }
+
+            el.opening.attrs.visit_with(self);
+            el.children.visit_with(self);
+            return;
+        }
+
+        el.visit_children_with(self);
+    }
+
+    fn visit_member_expr(&mut self, expr: &MemberExpr) {
+        if !member_prop_is(&expr.prop, "defaultProps") {
+            expr.visit_children_with(self);
+            return;
+        }
+
+        let Expr::Ident(obj) = &*expr.obj else {
+            self.unknown_access = true;
+            expr.visit_children_with(self);
+            return;
+        };
+
+        let id = obj.to_id();
+        if let Some(candidate) = self.candidates.get(&id) {
+            let is_definition = matches!(
+                candidate.definition,
+                Definition::Assign { target, .. } if target == expr.span
+            );
+            if !is_definition {
+                self.invalid.insert(id);
+            }
+        }
+    }
+
+    fn visit_setter_prop(&mut self, prop: &SetterProp) {
+        self.enter_function(|this| prop.visit_children_with(this));
+    }
+
+    fn visit_simple_assign_target(&mut self, target: &SimpleAssignTarget) {
+        if let SimpleAssignTarget::Ident(ident) = target
+            && self.candidates.contains_key(&ident.to_id())
+        {
+            self.invalid.insert(ident.to_id());
+        }
+
+        target.visit_children_with(self);
+    }
+
+    fn visit_super_prop_expr(&mut self, expr: &SuperPropExpr) {
+        if let SuperProp::Ident(prop) = &expr.prop
+            && prop.sym == "defaultProps"
+        {
+            self.unknown_access = true;
+        }
+
+        expr.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, expr: &UpdateExpr) {
+        if let Expr::Ident(ident) = &*expr.arg
+            && self.candidates.contains_key(&ident.to_id())
+        {
+            self.invalid.insert(ident.to_id());
+        }
+
+        expr.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, decl: &VarDeclarator) {
+        if let Pat::Ident(name) = &decl.name
+            && self.candidates.contains_key(&name.to_id())
+        {
+            decl.init.visit_with(self);
+            return;
+        }
+
+        decl.visit_children_with(self);
+    }
+}
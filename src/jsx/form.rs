@@ -0,0 +1,208 @@
+use super::{Jsx, is_empty_child, returned_expr};
+use swc_core::{
+    common::{DUMMY_SP, Span, Spanned, comments::Comments},
+    ecma::ast::*,
+    plugin::errors::HANDLER,
+};
+
+impl<C> Jsx<C>
+where
+    C: Comments,
+{
+    /// `<textarea>{text}</textarea>` => `<textarea defaultValue={text} />`,
+    /// runs after [`super::preserve_element_whitespace`] turned the text into
+    /// string literals.
+    ///
+    /// Inferno renders the children of a textarea as its initial content only,
+    /// so they are moved to `defaultValue` which it applies when mounting.
+    /// Children next to a `value` or `defaultValue` are kept and reported with
+    /// a warning, the prop decides the content.
+    pub(super) fn textarea_children_to_default_value(&self, el: &mut JSXElement) {
+        if el.children.iter().all(is_empty_child) {
+            return;
+        }
+
+        let has_value = el.opening.attrs.iter().any(|attr| {
+            matches!(
+                attr,
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    ..
+                }) if name.sym == "value" || name.sym == "defaultValue"
+            )
+        });
+        if has_value {
+            let children_span = el.children[0]
+                .span()
+                .with_hi(el.children[el.children.len() - 1].span_hi());
+            warn(
+                children_span,
+                "The children of a <textarea> with a `value` or `defaultValue` are ignored, remove them \
+                 or the prop",
+            );
+            return;
+        }
+
+        let mut parts = vec![];
+        for child in &el.children {
+            match child {
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::JSXEmptyExpr(..),
+                    ..
+                }) => {}
+                // Concatenating anything but text would render `undefined`,
+                // `false` or the commas of an array, leave it to the runtime
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) if self.is_text_expr(expr) => parts.push(expr.clone()),
+                _ => return,
+            }
+        }
+        if parts.is_empty() {
+            return;
+        }
+
+        // `"" + a + b` so that numbers are concatenated rather than added
+        let mut parts = parts.into_iter();
+        let first = parts.next().unwrap();
+        let value = if parts.len() == 0 {
+            first
+        } else {
+            let start = match *first {
+                Expr::Lit(Lit::Str(..)) => first,
+                _ => Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: BinaryOp::Add,
+                    left: "".into(),
+                    right: first,
+                })),
+            };
+            parts.fold(start, |left, right| {
+                Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: BinaryOp::Add,
+                    left,
+                    right,
+                }))
+            })
+        };
+
+        el.children.clear();
+        el.opening.attrs.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+            span: DUMMY_SP,
+            name: JSXAttrName::Ident(IdentName::new("defaultValue".into(), DUMMY_SP)),
+            value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                span: DUMMY_SP,
+                expr: JSXExpr::Expr(value),
+            })),
+        }));
+    }
+}
+
+/// Warns about `<option selected>` within a `<select value>`, Inferno selects
+/// the options matching the `value` of a controlled select and ignores
+/// `selected`.
+pub(super) fn check_select_options(el: &JSXElement) {
+    let is_controlled = el.opening.attrs.iter().any(|attr| {
+        matches!(
+            attr,
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                ..
+            }) if name.sym == "value"
+        )
+    });
+    if is_controlled {
+        check_options(&el.children);
+    }
+}
+
+fn check_options(children: &[JSXElementChild]) {
+    for child in children {
+        match child {
+            JSXElementChild::JSXElement(el) => check_option(el),
+            JSXElementChild::JSXFragment(frag) => check_options(&frag.children),
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => check_option_expr(expr),
+            _ => {}
+        }
+    }
+}
+
+/// `{cond && <option />}`, `{items.map((item) => <option />)}`
+fn check_option_expr(expr: &Expr) {
+    match expr {
+        Expr::JSXElement(el) => check_option(el),
+        Expr::JSXFragment(frag) => check_options(&frag.children),
+        Expr::Paren(ParenExpr { expr, .. }) => check_option_expr(expr),
+        Expr::Bin(BinExpr {
+            op: BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing,
+            right,
+            ..
+        }) => check_option_expr(right),
+        Expr::Cond(CondExpr { cons, alt, .. }) => {
+            check_option_expr(cons);
+            check_option_expr(alt);
+        }
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) if matches!(
+            &**callee,
+            Expr::Member(MemberExpr { prop: MemberProp::Ident(method), .. }) if method.sym == "map"
+        ) =>
+        {
+            let body = match args.first().map(|arg| &*arg.expr) {
+                Some(Expr::Arrow(ArrowExpr { body, .. })) => match &**body {
+                    BlockStmtOrExpr::Expr(expr) => Some(&**expr),
+                    BlockStmtOrExpr::BlockStmt(block) => returned_expr(block),
+                    #[cfg(swc_ast_unknown)]
+                    _ => None,
+                },
+                Some(Expr::Fn(FnExpr { function, .. })) => {
+                    function.body.as_ref().and_then(returned_expr)
+                }
+                _ => None,
+            };
+            if let Some(body) = body {
+                check_option_expr(body);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_option(el: &JSXElement) {
+    let JSXElementName::Ident(name) = &el.opening.name else {
+        return;
+    };
+    match &*name.sym {
+        "optgroup" => check_options(&el.children),
+        "option" => {
+            for attr in &el.opening.attrs {
+                if let JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    span,
+                    name: JSXAttrName::Ident(name),
+                    ..
+                }) = attr
+                    && name.sym == "selected"
+                {
+                    warn(
+                        *span,
+                        "`selected` on an <option> is ignored when the <select> has a `value`, \
+                         select the option through the `value` instead",
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn warn(span: Span, msg: &str) {
+    HANDLER.with(|handler| handler.struct_span_warn(span, msg).emit());
+}
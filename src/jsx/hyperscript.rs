@@ -0,0 +1,233 @@
+use swc_core::common::comments::Comments;
+use swc_core::common::{DUMMY_SP, Span, Spanned};
+use swc_core::ecma::ast::*;
+
+use super::Jsx;
+use super::create_element::{component_name, is_jsx_name, jsx_element};
+
+pub(super) const HYPERSCRIPT_SOURCE: &str = "inferno-hyperscript";
+
+/// A static selector like `div.card.active#main`
+struct Selector {
+    tag: String,
+    classes: Vec<String>,
+    id: Option<String>,
+}
+
+impl<C> Jsx<C>
+where
+    C: Comments,
+{
+    /// `h("div.card#main", props, children)` => `<div className="card" id="main" {...props}>{children}</div>`
+    ///
+    /// Selectors are parsed the way `inferno-hyperscript` does at runtime. Calls
+    /// whose result depends on runtime values, like a selector class merged with
+    /// a dynamic `className`, are left to the runtime `h`.
+    pub(super) fn hyperscript_to_jsx(&mut self, call: &CallExpr) -> Option<JSXElement> {
+        if call.args.iter().any(|arg| arg.spread.is_some()) {
+            return None;
+        }
+
+        let (tag, props, children) = match &call.args[..] {
+            [tag] => (tag, None, None),
+            // h("div", "text") and h("div", [..]) pass children in place of props
+            [tag, second] if is_children_literal(&second.expr) => (tag, None, Some(&second.expr)),
+            // Anything else may turn out to be children at runtime
+            [tag, props] if matches!(&*props.expr, Expr::Object(..) | Expr::Lit(Lit::Null(..))) => {
+                (tag, Some(&props.expr), None)
+            }
+            [tag, props, children] => (tag, Some(&props.expr), Some(&children.expr)),
+            _ => return None,
+        };
+
+        let (name, selector) = match &*tag.expr {
+            Expr::Lit(Lit::Str(s)) => {
+                let selector = parse_selector(s.value.as_str()?)?;
+                let name =
+                    JSXElementName::Ident(Ident::new_no_ctxt(selector.tag.as_str().into(), s.span));
+
+                (name, Some(selector))
+            }
+            tag => (component_name(tag)?, None),
+        };
+
+        let mut attrs = vec![];
+        if let Some(props) = props {
+            self.props_to_attrs(props, selector.is_some(), &mut attrs)?;
+        }
+
+        if attrs.iter().any(|attr| attr_name(attr) == Some("hooks")) {
+            return None;
+        }
+
+        if let Some(selector) = selector {
+            merge_selector(&mut attrs, selector, tag.expr.span())?;
+        }
+
+        let children = match children.map(|children| &**children) {
+            // `_children || props.children` skips falsy children like `0`
+            None => vec![],
+            Some(child) if is_falsy_literal(child) => vec![],
+            Some(Expr::Array(ArrayLit { elems, .. })) => elems
+                .iter()
+                .map(|elem| match elem {
+                    Some(ExprOrSpread { spread: None, expr }) => Some(self.call_child(expr)),
+                    _ => None,
+                })
+                .collect::<Option<_>>()?,
+            Some(child) => vec![self.call_child(child)],
+        };
+
+        Some(jsx_element(call.span, name, attrs, children))
+    }
+}
+
+/// `inferno-hyperscript` treats strings, numbers and arrays in place of props
+/// as children
+fn is_children_literal(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Lit(Lit::Str(..)) | Expr::Lit(Lit::Num(..)) | Expr::Array(..) | Expr::Tpl(..)
+    )
+}
+
+fn is_falsy_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(Lit::Null(..)) => true,
+        Expr::Lit(Lit::Bool(b)) => !b.value,
+        Expr::Lit(Lit::Num(n)) => n.value == 0.0 || n.value.is_nan(),
+        Expr::Lit(Lit::Str(s)) => s.value.is_empty(),
+        Expr::Tpl(tpl) => {
+            tpl.exprs.is_empty()
+                && tpl
+                    .quasis
+                    .iter()
+                    .all(|quasi| quasi.cooked.as_ref().is_some_and(|c| c.is_empty()))
+        }
+        _ => false,
+    }
+}
+
+fn attr_name(attr: &JSXAttrOrSpread) -> Option<&str> {
+    match attr {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::Ident(name),
+            ..
+        }) => Some(&name.sym),
+        _ => None,
+    }
+}
+
+/// Adds the classes and the id of the selector to the props. The selector
+/// classes come first and an `id` prop wins over the selector id.
+fn merge_selector(attrs: &mut Vec<JSXAttrOrSpread>, selector: Selector, span: Span) -> Option<()> {
+    let has_spread = attrs
+        .iter()
+        .any(|attr| matches!(attr, JSXAttrOrSpread::SpreadElement(..)));
+
+    if !selector.classes.is_empty() {
+        if has_spread {
+            return None;
+        }
+
+        let mut classes = selector.classes.join(" ");
+        if let Some(index) = attrs
+            .iter()
+            .position(|attr| matches!(attr_name(attr), Some("className" | "class")))
+        {
+            match attrs.remove(index) {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    value:
+                        Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                            expr: JSXExpr::Expr(value),
+                            ..
+                        })),
+                    ..
+                }) => match &*value {
+                    Expr::Lit(Lit::Str(s)) => {
+                        let class_name = s.value.as_str()?;
+                        if !class_name.is_empty() {
+                            classes.push(' ');
+                            classes.push_str(class_name);
+                        }
+                    }
+                    _ => return None,
+                },
+                _ => return None,
+            }
+        }
+
+        attrs.push(string_attr("className", &classes, span));
+    }
+
+    if let Some(id) = selector.id {
+        if has_spread {
+            return None;
+        }
+
+        if !attrs.iter().any(|attr| attr_name(attr) == Some("id")) {
+            attrs.push(string_attr("id", &id, span));
+        }
+    }
+
+    Some(())
+}
+
+fn string_attr(name: &str, value: &str, span: Span) -> JSXAttrOrSpread {
+    JSXAttrOrSpread::JSXAttr(JSXAttr {
+        span,
+        name: JSXAttrName::Ident(IdentName::new(name.into(), span)),
+        value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Str(Str {
+                span,
+                raw: None,
+                value: value.into(),
+            })))),
+        })),
+    })
+}
+
+/// `tag.class#id` where every part is `[a-zA-Z0-9_:-]+`, the tag defaults to
+/// `div` and is lowercased like at runtime
+fn parse_selector(selector: &str) -> Option<Selector> {
+    let mut parsed = Selector {
+        tag: String::new(),
+        classes: vec![],
+        id: None,
+    };
+
+    let mut rest = selector;
+    let mut is_first = true;
+    while !rest.is_empty() {
+        let prefix = rest.chars().next().filter(|c| matches!(c, '.' | '#'));
+        if prefix.is_some() {
+            rest = &rest[1..];
+        }
+
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | ':' | '-')))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return None;
+        }
+        let (part, tail) = rest.split_at(len);
+        rest = tail;
+
+        match prefix {
+            None if is_first => parsed.tag = part.to_ascii_lowercase(),
+            Some('.') => parsed.classes.push(part.into()),
+            Some('#') if parsed.id.is_none() => parsed.id = Some(part.into()),
+            _ => return None,
+        }
+
+        is_first = false;
+    }
+
+    if parsed.tag.is_empty() {
+        parsed.tag = "div".into();
+    }
+
+    (parsed.tag.starts_with(|c: char| c.is_ascii_lowercase()) && is_jsx_name(&parsed.tag))
+        .then_some(parsed)
+}
@@ -0,0 +1,748 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::atoms::Atom;
+use swc_core::common::comments::Comments;
+use swc_core::common::{DUMMY_SP, Spanned};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::visit::{
+    Visit, VisitMut, VisitMutWith, VisitWith, noop_visit_mut_type, noop_visit_type,
+};
+
+use super::attr::jsx_attr_value_to_expr;
+use super::{Jsx, is_component_vnode, is_empty_child};
+
+/// A functional component which only returns JSX, `const Label = ({ text }) => <span>{text}</span>`
+pub(super) struct InlineComponent {
+    params: ComponentParams,
+    /// A JSX element or fragment
+    body: Expr,
+    uses: FxHashMap<Atom, PropUse>,
+    /// Whether rendering the body calls or assigns anything, which a prop
+    /// with side effects must not be moved past
+    has_effects: bool,
+}
+
+enum ComponentParams {
+    None,
+    /// `(props) => ..`, only used as `props.name`
+    Props(Id),
+    /// `({ name, other: local }) => ..`
+    Destructured(Vec<(Atom, Id)>),
+}
+
+#[derive(Default, Clone, Copy)]
+struct PropUse {
+    count: usize,
+    /// Whether a reference is inside a function, which would read the value
+    /// when it is called rather than when the component is rendered
+    captured: bool,
+    /// Whether a reference is only evaluated depending on another value, like
+    /// the right side of `&&` or a branch of `?:`
+    conditional: bool,
+}
+
+impl<C> Jsx<C>
+where
+    C: Comments,
+{
+    /// Components which are declared at the top level of the module and are
+    /// not exported, unless annotated with `/* @inline */`
+    pub(super) fn collect_inline_components(
+        &self,
+        module: &Module,
+    ) -> FxHashMap<Id, InlineComponent> {
+        let mut exported = FxHashSet::default();
+        for item in &module.body {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    ..
+                })) => {
+                    for specifier in specifiers {
+                        if let ExportSpecifier::Named(ExportNamedSpecifier {
+                            orig: ModuleExportName::Ident(orig),
+                            ..
+                        }) = specifier
+                        {
+                            exported.insert(orig.to_id());
+                        }
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                    expr,
+                    ..
+                })) => {
+                    if let Expr::Ident(ident) = &**expr {
+                        exported.insert(ident.to_id());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut components = FxHashMap::default();
+        for item in &module.body {
+            let (decl, is_exported) = match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => (decl, false),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                    (decl, true)
+                }
+                _ => continue,
+            };
+
+            if is_exported && !self.has_inline_annotation(item) {
+                continue;
+            }
+
+            let candidates: Vec<(&Ident, Option<InlineComponent>)> = match decl {
+                Decl::Fn(FnDecl {
+                    ident, function, ..
+                }) => {
+                    vec![(ident, inline_function(function))]
+                }
+                Decl::Var(var) if var.kind == VarDeclKind::Const => var
+                    .decls
+                    .iter()
+                    .filter_map(|decl| {
+                        let Pat::Ident(name) = &decl.name else {
+                            return None;
+                        };
+
+                        Some((&name.id, decl.init.as_deref().and_then(inline_init)))
+                    })
+                    .collect(),
+                _ => continue,
+            };
+
+            for (ident, component) in candidates {
+                if !is_component_vnode(ident)
+                    || (exported.contains(&ident.to_id()) && !self.has_inline_annotation(item))
+                {
+                    continue;
+                }
+
+                if let Some(component) = component {
+                    components.insert(ident.to_id(), component);
+                }
+            }
+        }
+
+        // Properties like `defaultProps` or `defaultHooks`, reassignments and
+        // any other use of the component change what it renders
+        let mut mutated = MutatedComponents {
+            components: &components,
+            mutated: FxHashSet::default(),
+        };
+        module.visit_with(&mut mutated);
+        let mutated = mutated.mutated;
+        components.retain(|id, _| !mutated.contains(id));
+
+        // Inlining a component which renders itself, directly or through
+        // another one, would never end
+        let refs: FxHashMap<Id, FxHashSet<Id>> = components
+            .iter()
+            .map(|(id, component)| {
+                let mut refs = referenced_idents(&component.body);
+                refs.retain(|id| components.contains_key(id));
+                (id.clone(), refs)
+            })
+            .collect();
+        components.retain(|id, _| !is_recursive(id, &refs));
+
+        components
+    }
+
+    fn has_inline_annotation(&self, item: &ModuleItem) -> bool {
+        self.comments.as_ref().is_some_and(|comments| {
+            comments
+                .get_leading(item.span().lo)
+                .is_some_and(|leading| leading.iter().any(|c| c.text.contains("@inline")))
+        })
+    }
+
+    /// The body of the component with the props of `el` substituted, when
+    /// every prop can be moved into it without changing what is evaluated
+    pub(super) fn inline_component(&self, el: &JSXElement) -> Option<Expr> {
+        if self.inlinable_components.is_empty() {
+            return None;
+        }
+
+        let JSXElementName::Ident(name) = &el.opening.name else {
+            return None;
+        };
+        let id = name.to_id();
+        let component = self.inlinable_components.get(&id)?;
+
+        let mut props: FxHashMap<Atom, Box<Expr>> = FxHashMap::default();
+        let mut key = None;
+        for attr in &el.opening.attrs {
+            let JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(attr_name),
+                value,
+                ..
+            }) = attr
+            else {
+                return None;
+            };
+
+            let value = match value {
+                Some(value) => jsx_attr_value_to_expr(value.clone())?,
+                None => true.into(),
+            };
+
+            match &*attr_name.sym {
+                "key" => key = Some(value),
+                "ref" => return None,
+                name if name.starts_with('$') || name.starts_with("onComponent") => return None,
+                name => {
+                    if props.insert(name.into(), value).is_some() {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        if el.children.iter().any(|child| !is_empty_child(child)) {
+            let children = Atom::from("children");
+            if component.uses.contains_key(&children) || props.contains_key(&children) {
+                return None;
+            }
+
+            // Children which are not rendered are only dropped when that
+            // does not skip any side effect
+            let children_are_pure = el.children.iter().all(|child| match child {
+                JSXElementChild::JSXText(..) => true,
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) => is_pure(expr),
+                _ => is_empty_child(child),
+            });
+            if !children_are_pure {
+                return None;
+            }
+        }
+
+        // Props which are moved past each other must not have side effects
+        if props.values().filter(|value| !is_pure(value)).count() > 1 {
+            return None;
+        }
+
+        for (name, value) in &props {
+            let usage = component.uses.get(name).copied().unwrap_or_default();
+            let allowed = match usage.count {
+                0 => is_pure(value),
+                _ if usage.captured => matches!(&**value, Expr::Lit(..)),
+                1 => is_pure(value) || !(usage.conditional || component.has_effects),
+                _ => is_cheap(value),
+            };
+
+            if !allowed {
+                return None;
+            }
+        }
+
+        let mut body = component.body.clone();
+        body.visit_mut_with(&mut PropSubstituter {
+            params: &component.params,
+            props: &props,
+        });
+
+        // The root takes the place of the element, so it gets its own pure
+        // annotation
+        match &mut body {
+            Expr::JSXElement(root) => root.span = el.span,
+            Expr::JSXFragment(root) => root.span = el.span,
+            _ => {}
+        }
+
+        if let Some(key) = key {
+            let Expr::JSXElement(root) = &mut body else {
+                return None;
+            };
+
+            root.opening.attrs.retain(|attr| {
+                !matches!(attr, JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    ..
+                }) if name.sym == "key")
+            });
+            root.opening.attrs.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+                span: DUMMY_SP,
+                name: JSXAttrName::Ident(IdentName::new("key".into(), DUMMY_SP)),
+                value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    span: DUMMY_SP,
+                    expr: JSXExpr::Expr(key),
+                })),
+            }));
+        }
+
+        Some(body)
+    }
+}
+
+fn inline_init(init: &Expr) -> Option<InlineComponent> {
+    match init {
+        Expr::Paren(ParenExpr { expr, .. }) => inline_init(expr),
+        Expr::Arrow(arrow) if !arrow.is_async && !arrow.is_generator => {
+            let BlockStmtOrExpr::Expr(body) = &*arrow.body else {
+                return None;
+            };
+
+            inline_body(arrow.params.iter().collect(), body)
+        }
+        Expr::Fn(FnExpr { function, .. }) => inline_function(function),
+        _ => None,
+    }
+}
+
+/// `function Label(props) { return <span />; }`
+fn inline_function(function: &Function) -> Option<InlineComponent> {
+    if function.is_async || function.is_generator {
+        return None;
+    }
+
+    let [
+        Stmt::Return(ReturnStmt {
+            arg: Some(body), ..
+        }),
+    ] = &function.body.as_ref()?.stmts[..]
+    else {
+        return None;
+    };
+
+    if uses_function_scope(body) {
+        return None;
+    }
+
+    inline_body(
+        function.params.iter().map(|param| &param.pat).collect(),
+        body,
+    )
+}
+
+fn inline_body(params: Vec<&Pat>, body: &Expr) -> Option<InlineComponent> {
+    let body = match body {
+        Expr::Paren(ParenExpr { expr, .. }) => return inline_body(params, expr),
+        Expr::JSXElement(..) => body.clone(),
+        // An empty fragment renders nothing rather than a vNode
+        Expr::JSXFragment(frag) if !frag.children.iter().all(is_empty_child) => body.clone(),
+        _ => return None,
+    };
+
+    let params = match &params[..] {
+        [] => ComponentParams::None,
+        [Pat::Ident(props)] => ComponentParams::Props(props.to_id()),
+        [Pat::Object(ObjectPat { props, .. })] => {
+            let mut bindings = vec![];
+            for prop in props {
+                let (key, local) = match prop {
+                    ObjectPatProp::Assign(AssignPatProp {
+                        key, value: None, ..
+                    }) => (key.sym.clone(), key.to_id()),
+                    ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                        let key = match key {
+                            PropName::Ident(key) => key.sym.clone(),
+                            PropName::Str(key) => key.value.as_str()?.into(),
+                            _ => return None,
+                        };
+                        let Pat::Ident(local) = &**value else {
+                            return None;
+                        };
+
+                        (key, local.to_id())
+                    }
+                    _ => return None,
+                };
+
+                bindings.push((key, local));
+            }
+
+            ComponentParams::Destructured(bindings)
+        }
+        _ => return None,
+    };
+
+    let mut finder = PropUses {
+        params: &params,
+        uses: FxHashMap::default(),
+        functions: 0,
+        conditionals: 0,
+        invalid: false,
+    };
+    body.visit_with(&mut finder);
+    if finder.invalid {
+        return None;
+    }
+    let uses = finder.uses;
+    let has_effects = has_effects(&body);
+
+    Some(InlineComponent {
+        params,
+        body,
+        uses,
+        has_effects,
+    })
+}
+
+/// Values which can be evaluated any number of times, or not at all
+fn is_cheap(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(..) | Expr::Ident(..) => true,
+        Expr::Paren(ParenExpr { expr, .. }) => is_cheap(expr),
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(..),
+            ..
+        }) => is_cheap(obj),
+        _ => false,
+    }
+}
+
+/// Values without side effects, which can be dropped or reordered
+fn is_pure(expr: &Expr) -> bool {
+    match expr {
+        Expr::Arrow(..) | Expr::Fn(..) => true,
+        Expr::Tpl(tpl) => tpl.exprs.iter().all(|expr| is_pure(expr)),
+        _ => is_cheap(expr),
+    }
+}
+
+fn referenced_idents(body: &Expr) -> FxHashSet<Id> {
+    struct Finder(FxHashSet<Id>);
+
+    impl Visit for Finder {
+        noop_visit_type!();
+
+        fn visit_ident(&mut self, ident: &Ident) {
+            self.0.insert(ident.to_id());
+        }
+    }
+
+    let mut finder = Finder(FxHashSet::default());
+    body.visit_with(&mut finder);
+    finder.0
+}
+
+fn is_recursive(id: &Id, refs: &FxHashMap<Id, FxHashSet<Id>>) -> bool {
+    let mut visited = FxHashSet::default();
+    let mut stack: Vec<&Id> = refs[id].iter().collect();
+
+    while let Some(next) = stack.pop() {
+        if next == id {
+            return true;
+        }
+
+        if visited.insert(next) {
+            stack.extend(refs[next].iter());
+        }
+    }
+
+    false
+}
+
+/// Calls, assignments and other expressions which run when the body is
+/// evaluated, functions in it only run when they are called
+fn has_effects(body: &Expr) -> bool {
+    struct Finder(bool);
+
+    impl Visit for Finder {
+        noop_visit_type!();
+
+        fn visit_expr(&mut self, expr: &Expr) {
+            self.0 |= match expr {
+                Expr::Call(..)
+                | Expr::New(..)
+                | Expr::TaggedTpl(..)
+                | Expr::Assign(..)
+                | Expr::Update(..)
+                | Expr::Await(..)
+                | Expr::Yield(..) => true,
+                Expr::Unary(UnaryExpr { op, .. }) => *op == UnaryOp::Delete,
+                Expr::OptChain(OptChainExpr { base, .. }) => {
+                    matches!(&**base, OptChainBase::Call(..))
+                }
+                _ => false,
+            };
+            expr.visit_children_with(self);
+        }
+
+        fn visit_function(&mut self, _: &Function) {}
+
+        fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+        fn visit_class(&mut self, _: &Class) {}
+    }
+
+    let mut finder = Finder(false);
+    body.visit_with(&mut finder);
+    finder.0
+}
+
+/// `this` and `arguments` differ once the body is inlined
+fn uses_function_scope(body: &Expr) -> bool {
+    struct Finder(bool);
+
+    impl Visit for Finder {
+        noop_visit_type!();
+
+        fn visit_this_expr(&mut self, _: &ThisExpr) {
+            self.0 = true;
+        }
+
+        fn visit_ident(&mut self, ident: &Ident) {
+            self.0 |= ident.sym == "arguments";
+        }
+
+        fn visit_jsx_element_name(&mut self, name: &JSXElementName) {
+            self.0 |= matches!(name, JSXElementName::Ident(ident) if ident.sym == "this");
+            name.visit_children_with(self);
+        }
+
+        fn visit_function(&mut self, _: &Function) {}
+
+        fn visit_class(&mut self, _: &Class) {}
+    }
+
+    let mut finder = Finder(false);
+    body.visit_with(&mut finder);
+    finder.0
+}
+
+/// Counts the references of each prop in the body, any other use of the
+/// props object makes the component not inlineable
+struct PropUses<'a> {
+    params: &'a ComponentParams,
+    uses: FxHashMap<Atom, PropUse>,
+    functions: usize,
+    conditionals: usize,
+    invalid: bool,
+}
+
+impl PropUses<'_> {
+    fn record(&mut self, name: &Atom) {
+        let usage = self.uses.entry(name.clone()).or_default();
+        usage.count += 1;
+        usage.captured |= self.functions > 0;
+        usage.conditional |= self.conditionals > 0;
+    }
+
+    fn visit_conditional<N: VisitWith<Self>>(&mut self, node: &N) {
+        self.conditionals += 1;
+        node.visit_with(self);
+        self.conditionals -= 1;
+    }
+}
+
+impl Visit for PropUses<'_> {
+    noop_visit_type!();
+
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        if let ComponentParams::Props(props) = self.params
+            && let Expr::Ident(obj) = &*member.obj
+            && obj.to_id() == *props
+        {
+            match &member.prop {
+                MemberProp::Ident(prop) => self.record(&prop.sym),
+                _ => self.invalid = true,
+            }
+            return;
+        }
+
+        member.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        match self.params {
+            ComponentParams::Props(props) if ident.to_id() == *props => self.invalid = true,
+            ComponentParams::Destructured(bindings) => {
+                if let Some((key, _)) = bindings.iter().find(|(_, local)| ident.to_id() == *local) {
+                    let key = key.clone();
+                    self.record(&key);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Element names can not be replaced by arbitrary expressions
+    fn visit_jsx_element_name(&mut self, name: &JSXElementName) {
+        let mut root = None;
+        match name {
+            JSXElementName::Ident(ident) => root = Some(ident),
+            JSXElementName::JSXMemberExpr(member) => {
+                let mut obj = &member.obj;
+                while let JSXObject::JSXMemberExpr(member) = obj {
+                    obj = &member.obj;
+                }
+                if let JSXObject::Ident(ident) = obj {
+                    root = Some(ident);
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(root) = root {
+            let id = root.to_id();
+            self.invalid |= match self.params {
+                ComponentParams::None => false,
+                ComponentParams::Props(props) => id == *props,
+                ComponentParams::Destructured(bindings) => {
+                    bindings.iter().any(|(_, local)| *local == id)
+                }
+            };
+        }
+    }
+
+    fn visit_bin_expr(&mut self, bin: &BinExpr) {
+        bin.left.visit_with(self);
+        match bin.op {
+            BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+                self.visit_conditional(&bin.right)
+            }
+            _ => bin.right.visit_with(self),
+        }
+    }
+
+    fn visit_cond_expr(&mut self, cond: &CondExpr) {
+        cond.test.visit_with(self);
+        self.visit_conditional(&cond.cons);
+        self.visit_conditional(&cond.alt);
+    }
+
+    fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+        assign.left.visit_with(self);
+        match assign.op {
+            AssignOp::AndAssign | AssignOp::OrAssign | AssignOp::NullishAssign => {
+                self.visit_conditional(&assign.right)
+            }
+            _ => assign.right.visit_with(self),
+        }
+    }
+
+    // `a?.b(prop)` skips everything after the `?.` when `a` is nullish
+    fn visit_opt_chain_expr(&mut self, chain: &OptChainExpr) {
+        self.visit_conditional(&chain.base);
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.functions += 1;
+        function.visit_children_with(self);
+        self.functions -= 1;
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        self.functions += 1;
+        arrow.visit_children_with(self);
+        self.functions -= 1;
+    }
+}
+
+/// Components which are referenced other than by rendering them, a property
+/// access like `Foo.defaultProps = ..`, a reassignment or passing them to a
+/// call like `Object.assign(Foo, ..)` could change what they render
+struct MutatedComponents<'a> {
+    components: &'a FxHashMap<Id, InlineComponent>,
+    mutated: FxHashSet<Id>,
+}
+
+impl Visit for MutatedComponents<'_> {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if self.components.contains_key(&ident.to_id()) {
+            self.mutated.insert(ident.to_id());
+        }
+    }
+
+    // Declarations and element names are not references to the value
+    fn visit_binding_ident(&mut self, _: &BindingIdent) {}
+
+    fn visit_simple_assign_target(&mut self, target: &SimpleAssignTarget) {
+        match target {
+            SimpleAssignTarget::Ident(ident) => self.visit_ident(&ident.id),
+            _ => target.visit_children_with(self),
+        }
+    }
+
+    fn visit_fn_decl(&mut self, decl: &FnDecl) {
+        decl.function.visit_with(self);
+    }
+
+    fn visit_jsx_element_name(&mut self, name: &JSXElementName) {
+        if !matches!(name, JSXElementName::Ident(..)) {
+            name.visit_children_with(self);
+        }
+    }
+}
+
+struct PropSubstituter<'a> {
+    params: &'a ComponentParams,
+    props: &'a FxHashMap<Atom, Box<Expr>>,
+}
+
+impl PropSubstituter<'_> {
+    /// The value passed for `key`, `undefined` when it was not passed
+    fn value(&self, key: &Atom) -> Expr {
+        match self.props.get(key) {
+            Some(value) => (**value).clone(),
+            None => Expr::Unary(UnaryExpr {
+                span: DUMMY_SP,
+                op: UnaryOp::Void,
+                arg: Box::new(0.0.into()),
+            }),
+        }
+    }
+
+    fn binding_key(&self, ident: &Ident) -> Option<Atom> {
+        let ComponentParams::Destructured(bindings) = self.params else {
+            return None;
+        };
+
+        let id = ident.to_id();
+        bindings
+            .iter()
+            .find(|(_, local)| *local == id)
+            .map(|(key, _)| key.clone())
+    }
+}
+
+impl VisitMut for PropSubstituter<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Ident(ident) => {
+                if let Some(key) = self.binding_key(ident) {
+                    *expr = self.value(&key);
+                }
+            }
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) if matches!(
+                (&**obj, self.params),
+                (Expr::Ident(obj), ComponentParams::Props(props)) if obj.to_id() == *props
+            ) =>
+            {
+                *expr = self.value(&prop.sym);
+            }
+            _ => expr.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        if let Prop::Shorthand(ident) = prop
+            && let Some(key) = self.binding_key(ident)
+        {
+            *prop = Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(IdentName::new(ident.sym.clone(), ident.span)),
+                value: Box::new(self.value(&key)),
+            });
+            return;
+        }
+
+        prop.visit_mut_children_with(self);
+    }
+}
@@ -0,0 +1,36 @@
+use swc_core::common::BytePos;
+use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::drop_span;
+use swc_core::ecma::visit::{VisitMut, VisitMutWith, noop_visit_mut_type};
+use swc_ecma_parser::{Parser, StringInput, Syntax};
+
+/// Declarations of a helper written in JavaScript, which is added to the
+/// output rather than imported. References to helpers named in `locals` are
+/// bound to their private identifiers.
+pub(super) fn parse_inline_helper(source: &str, locals: &[(&'static str, Ident)]) -> Vec<Stmt> {
+    let mut parser = Parser::new(
+        Syntax::default(),
+        StringInput::new(source, BytePos(0), BytePos(source.len() as u32)),
+        None,
+    );
+    let mut script = drop_span(
+        parser
+            .parse_script()
+            .expect("failed to parse the source of an inline helper"),
+    );
+    script.visit_mut_with(&mut HelperRenamer(locals));
+
+    script.body
+}
+
+struct HelperRenamer<'a>(&'a [(&'static str, Ident)]);
+
+impl VisitMut for HelperRenamer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_ident(&mut self, ident: &mut Ident) {
+        if let Some((_, local)) = self.0.iter().find(|(name, _)| ident.sym == **name) {
+            *ident = local.clone();
+        }
+    }
+}
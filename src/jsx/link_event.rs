@@ -0,0 +1,668 @@
+use rustc_hash::FxHashSet;
+use swc_core::common::comments::Comments;
+use swc_core::common::{DUMMY_SP, Mark};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::{ExprFactory, prepend_stmts, private_ident};
+use swc_core::ecma::visit::{
+    Visit, VisitMut, VisitMutWith, VisitWith, noop_visit_mut_type, noop_visit_type,
+};
+
+use super::{Jsx, RuntimeHelper, is_component_vnode};
+
+impl<C> Jsx<C>
+where
+    C: Comments,
+{
+    /// Rewrites inline arrow event handlers of elements which close over a
+    /// single value, `onClick={() => this.select(item.id)}`, to
+    /// `onClick={linkEvent(item, handleClick)}` with the handler declared in
+    /// the outermost scope which has everything else it refers to
+    pub(super) fn hoist_link_events<N>(&mut self, node: &mut N) -> Vec<Stmt>
+    where
+        N: VisitWith<Reassigned>
+            + VisitWith<ScopeBindings>
+            + for<'a> VisitMutWith<LinkEvents<'a, C>>,
+    {
+        let mut reassigned = Reassigned::default();
+        node.visit_with(&mut reassigned);
+
+        let mut module = ScopeBindings::default();
+        node.visit_with(&mut module);
+
+        let mut linker = LinkEvents {
+            unresolved_mark: self.unresolved_mark,
+            jsx: self,
+            reassigned: reassigned.0,
+            module_block_bindings: module.block_bindings,
+            scopes: vec![],
+            hoisted: vec![],
+        };
+        node.visit_mut_with(&mut linker);
+
+        linker.hoisted
+    }
+}
+
+pub(super) struct LinkEvents<'a, C>
+where
+    C: Comments,
+{
+    unresolved_mark: Mark,
+    jsx: &'a mut Jsx<C>,
+    reassigned: FxHashSet<Id>,
+    /// Declarations of blocks at the top level, like the variable of a `for`
+    /// loop, which handlers declared at the top level can not see
+    module_block_bindings: FxHashSet<Id>,
+    scopes: Vec<Scope>,
+    /// Handlers declared at the top level
+    hoisted: Vec<Stmt>,
+}
+
+#[derive(Default)]
+struct Scope {
+    /// Parameters and declarations which are visible in the whole function
+    bindings: FxHashSet<Id>,
+    /// Declarations of nested blocks, a handler declared at the start of the
+    /// function can not see them
+    block_bindings: FxHashSet<Id>,
+    /// Whether the scope has its own `this`, unlike arrow functions
+    binds_this: bool,
+    /// Whether handlers can be declared at the start of the scope, which
+    /// needs a block body
+    can_hoist: bool,
+    hoisted: Vec<Stmt>,
+}
+
+/// Where a value referenced by a handler comes from, by the depth of its
+/// scope where `0` is the module or a global
+#[derive(Clone, Copy)]
+struct Origin {
+    depth: usize,
+    in_block: bool,
+}
+
+enum Captured {
+    This,
+    Ident(Ident),
+}
+
+impl<C> LinkEvents<'_, C>
+where
+    C: Comments,
+{
+    fn enter<T>(&mut self, scope: Scope, visit: impl FnOnce(&mut Self) -> T) -> (T, Vec<Stmt>) {
+        self.scopes.push(scope);
+        let result = visit(self);
+        let scope = self.scopes.pop().unwrap_or_default();
+        (result, scope.hoisted)
+    }
+
+    fn origin(&self, captured: &Captured) -> Origin {
+        let top_level = Origin {
+            depth: 0,
+            in_block: false,
+        };
+
+        match captured {
+            Captured::This => self
+                .scopes
+                .iter()
+                .rposition(|scope| scope.binds_this)
+                .map_or(top_level, |index| Origin {
+                    depth: index + 1,
+                    in_block: false,
+                }),
+            Captured::Ident(ident) => {
+                let id = ident.to_id();
+                self.scopes
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .find_map(|(index, scope)| {
+                        let in_block = scope.block_bindings.contains(&id);
+                        (in_block || scope.bindings.contains(&id)).then_some(Origin {
+                            depth: index + 1,
+                            in_block,
+                        })
+                    })
+                    .unwrap_or(Origin {
+                        depth: 0,
+                        in_block: self.module_block_bindings.contains(&id),
+                    })
+            }
+        }
+    }
+
+    fn link_event(&mut self, attr: &mut JSXAttr) -> bool {
+        let JSXAttrName::Ident(name) = &attr.name else {
+            return false;
+        };
+        let Some(event) = name.sym.strip_prefix("on") else {
+            return false;
+        };
+        if !event.starts_with(|c: char| c.is_ascii_uppercase()) {
+            return false;
+        }
+
+        let Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            ..
+        })) = &mut attr.value
+        else {
+            return false;
+        };
+        let Expr::Arrow(arrow) = &mut **expr else {
+            return false;
+        };
+        if arrow.is_async || arrow.is_generator || arrow.params.len() > 1 || !calls_once(arrow) {
+            return false;
+        }
+
+        let mut free = FreeRefs::new(arrow);
+        arrow.visit_with(&mut free);
+        if free.invalid {
+            return false;
+        }
+
+        let mut origins = vec![];
+        for captured in &free.refs {
+            if let Captured::Ident(ident) = captured
+                && ident.ctxt.outer() == self.unresolved_mark
+            {
+                continue;
+            }
+
+            origins.push(self.origin(captured));
+        }
+
+        // The value is the only reference which comes from a deeper scope than
+        // the one the handler is declared in
+        let Some(value_index) = (0..origins.len()).max_by_key(|&index| origins[index].depth) else {
+            return false;
+        };
+        let value_depth = origins[value_index].depth;
+        let mut target = 0;
+        for (index, origin) in origins.iter().enumerate() {
+            if index == value_index {
+                continue;
+            }
+            if origin.depth == value_depth || origin.in_block {
+                return false;
+            }
+
+            target = target.max(origin.depth);
+        }
+
+        if value_depth == 0 || (target > 0 && !self.scopes[target - 1].can_hoist) {
+            return false;
+        }
+
+        let resolved: Vec<&Captured> = free
+            .refs
+            .iter()
+            .filter(|captured| {
+                !matches!(captured, Captured::Ident(ident) if ident.ctxt.outer() == self.unresolved_mark)
+            })
+            .collect();
+        let (value, param) = match resolved[value_index] {
+            Captured::This => {
+                let this = private_ident!("self");
+                arrow.body.visit_mut_with(&mut ReplaceThis(this.clone()));
+                (Expr::This(ThisExpr { span: DUMMY_SP }), this)
+            }
+            // linkEvent reads the value when the element is rendered, rather
+            // than when the event fires
+            Captured::Ident(ident) if self.reassigned.contains(&ident.to_id()) => return false,
+            Captured::Ident(ident) => (Expr::Ident(ident.clone()), ident.clone()),
+        };
+
+        let handler = private_ident!(format!("handle{event}"));
+        let mut arrow = arrow.clone();
+        arrow.span = DUMMY_SP;
+        arrow.params.insert(0, param.into());
+
+        let decl = Stmt::Decl(Decl::Var(Box::new(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Const,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: handler.clone().into(),
+                init: Some(Box::new(Expr::Arrow(arrow))),
+                definite: false,
+            }],
+            ..Default::default()
+        })));
+        match target {
+            0 => self.hoisted.push(decl),
+            _ => self.scopes[target - 1].hoisted.push(decl),
+        }
+
+        **expr = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: self.jsx.helper_callee(RuntimeHelper::LinkEvent),
+            args: vec![value.as_arg(), handler.as_arg()],
+            ..Default::default()
+        });
+
+        true
+    }
+}
+
+impl<C> VisitMut for LinkEvents<'_, C>
+where
+    C: Comments,
+{
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+        let mut scope = Scope {
+            can_hoist: matches!(&*arrow.body, BlockStmtOrExpr::BlockStmt(..)),
+            ..Default::default()
+        };
+        scope.collect(&arrow.params);
+        if let BlockStmtOrExpr::BlockStmt(body) = &*arrow.body {
+            scope.collect(&body.stmts);
+        }
+
+        let ((), hoisted) = self.enter(scope, |this| arrow.visit_mut_children_with(this));
+        if let BlockStmtOrExpr::BlockStmt(body) = &mut *arrow.body {
+            prepend_stmts(&mut body.stmts, hoisted.into_iter());
+        }
+    }
+
+    fn visit_mut_class_prop(&mut self, prop: &mut ClassProp) {
+        prop.key.visit_mut_with(self);
+
+        // Initializers see the instance as `this`
+        let scope = Scope {
+            binds_this: true,
+            ..Default::default()
+        };
+        self.enter(scope, |this| prop.value.visit_mut_with(this));
+    }
+
+    fn visit_mut_constructor(&mut self, constructor: &mut Constructor) {
+        let mut scope = Scope {
+            binds_this: true,
+            can_hoist: constructor.body.is_some(),
+            ..Default::default()
+        };
+        scope.collect(&constructor.params);
+        if let Some(body) = &constructor.body {
+            scope.collect(&body.stmts);
+        }
+
+        let ((), hoisted) = self.enter(scope, |this| constructor.visit_mut_children_with(this));
+        if let Some(body) = &mut constructor.body {
+            prepend_stmts(&mut body.stmts, hoisted.into_iter());
+        }
+    }
+
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        let mut scope = Scope {
+            binds_this: true,
+            can_hoist: function.body.is_some(),
+            ..Default::default()
+        };
+        scope.collect(&function.params);
+        if let Some(body) = &function.body {
+            scope.collect(&body.stmts);
+        }
+
+        let ((), hoisted) = self.enter(scope, |this| function.visit_mut_children_with(this));
+        if let Some(body) = &mut function.body {
+            prepend_stmts(&mut body.stmts, hoisted.into_iter());
+        }
+    }
+
+    fn visit_mut_getter_prop(&mut self, prop: &mut GetterProp) {
+        let mut scope = Scope {
+            binds_this: true,
+            can_hoist: prop.body.is_some(),
+            ..Default::default()
+        };
+        if let Some(body) = &prop.body {
+            scope.collect(&body.stmts);
+        }
+
+        let ((), hoisted) = self.enter(scope, |this| prop.visit_mut_children_with(this));
+        if let Some(body) = &mut prop.body {
+            prepend_stmts(&mut body.stmts, hoisted.into_iter());
+        }
+    }
+
+    fn visit_mut_jsx_opening_element(&mut self, el: &mut JSXOpeningElement) {
+        let is_element =
+            matches!(&el.name, JSXElementName::Ident(name) if !is_component_vnode(name));
+
+        for attr in &mut el.attrs {
+            if let JSXAttrOrSpread::JSXAttr(attr) = attr
+                && is_element
+                && self.link_event(attr)
+            {
+                continue;
+            }
+
+            attr.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_setter_prop(&mut self, prop: &mut SetterProp) {
+        let mut scope = Scope {
+            binds_this: true,
+            can_hoist: prop.body.is_some(),
+            ..Default::default()
+        };
+        scope.collect(&prop.param);
+        if let Some(body) = &prop.body {
+            scope.collect(&body.stmts);
+        }
+
+        let ((), hoisted) = self.enter(scope, |this| prop.visit_mut_children_with(this));
+        if let Some(body) = &mut prop.body {
+            prepend_stmts(&mut body.stmts, hoisted.into_iter());
+        }
+    }
+
+    fn visit_mut_static_block(&mut self, block: &mut StaticBlock) {
+        let mut scope = Scope {
+            binds_this: true,
+            can_hoist: true,
+            ..Default::default()
+        };
+        scope.collect(&block.body.stmts);
+
+        let ((), hoisted) = self.enter(scope, |this| block.visit_mut_children_with(this));
+        prepend_stmts(&mut block.body.stmts, hoisted.into_iter());
+    }
+}
+
+impl Scope {
+    /// Adds the bindings of parameters or of the statements of the body
+    fn collect<N>(&mut self, node: &N)
+    where
+        N: VisitWith<ScopeBindings>,
+    {
+        let mut bindings = ScopeBindings::default();
+        node.visit_with(&mut bindings);
+
+        self.bindings.extend(bindings.bindings);
+        self.block_bindings.extend(bindings.block_bindings);
+    }
+}
+
+/// A single call, `() => select(item)` or `() => { select(item); }`
+fn calls_once(arrow: &ArrowExpr) -> bool {
+    match &*arrow.body {
+        BlockStmtOrExpr::Expr(expr) => matches!(&**expr, Expr::Call(..)),
+        BlockStmtOrExpr::BlockStmt(BlockStmt { stmts, .. }) => {
+            matches!(&stmts[..], [Stmt::Expr(ExprStmt { expr, .. })] if matches!(&**expr, Expr::Call(..)))
+        }
+        #[cfg(swc_ast_unknown)]
+        _ => false,
+    }
+}
+
+/// Bindings declared by a function or the module, without those of nested
+/// functions
+#[derive(Default)]
+pub(super) struct ScopeBindings {
+    bindings: FxHashSet<Id>,
+    block_bindings: FxHashSet<Id>,
+    blocks: usize,
+}
+
+impl ScopeBindings {
+    fn declare(&mut self, ident: &Ident, block_scoped: bool) {
+        if block_scoped && self.blocks > 0 {
+            self.block_bindings.insert(ident.to_id());
+        } else {
+            self.bindings.insert(ident.to_id());
+        }
+    }
+
+    fn enter_block(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.blocks += 1;
+        visit(self);
+        self.blocks -= 1;
+    }
+}
+
+impl Visit for ScopeBindings {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_binding_ident(&mut self, ident: &BindingIdent) {
+        // Parameters are declared outside of any block, catch clauses inside
+        self.declare(ident, true);
+    }
+
+    fn visit_block_stmt(&mut self, block: &BlockStmt) {
+        self.enter_block(|this| block.visit_children_with(this));
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause) {
+        self.enter_block(|this| clause.visit_children_with(this));
+    }
+
+    fn visit_class(&mut self, _: &Class) {}
+
+    fn visit_class_decl(&mut self, decl: &ClassDecl) {
+        self.declare(&decl.ident, true);
+    }
+
+    fn visit_constructor(&mut self, _: &Constructor) {}
+
+    fn visit_expr(&mut self, _: &Expr) {}
+
+    fn visit_fn_decl(&mut self, decl: &FnDecl) {
+        self.declare(&decl.ident, true);
+    }
+
+    fn visit_for_in_stmt(&mut self, stmt: &ForInStmt) {
+        self.enter_block(|this| stmt.visit_children_with(this));
+    }
+
+    fn visit_for_of_stmt(&mut self, stmt: &ForOfStmt) {
+        self.enter_block(|this| stmt.visit_children_with(this));
+    }
+
+    fn visit_for_stmt(&mut self, stmt: &ForStmt) {
+        self.enter_block(|this| stmt.visit_children_with(this));
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_switch_stmt(&mut self, stmt: &SwitchStmt) {
+        self.enter_block(|this| stmt.visit_children_with(this));
+    }
+
+    fn visit_var_decl(&mut self, decl: &VarDecl) {
+        let mut names = ScopeBindings::default();
+        for declarator in &decl.decls {
+            declarator.name.visit_with(&mut names);
+        }
+
+        for id in names.bindings {
+            if decl.kind != VarDeclKind::Var && self.blocks > 0 {
+                self.block_bindings.insert(id);
+            } else {
+                self.bindings.insert(id);
+            }
+        }
+    }
+}
+
+/// Values a handler refers to without declaring them
+struct FreeRefs {
+    bound: FxHashSet<Id>,
+    refs: Vec<Captured>,
+    seen: FxHashSet<Id>,
+    /// Nested functions with their own `this`
+    functions: usize,
+    /// `arguments`, `super`, `new.target` or JSX, which can not be moved
+    invalid: bool,
+}
+
+impl FreeRefs {
+    fn new(arrow: &ArrowExpr) -> Self {
+        let mut bound = AllBindings::default();
+        arrow.visit_with(&mut bound);
+
+        FreeRefs {
+            bound: bound.0,
+            refs: vec![],
+            seen: FxHashSet::default(),
+            functions: 0,
+            invalid: false,
+        }
+    }
+}
+
+impl Visit for FreeRefs {
+    noop_visit_type!();
+
+    fn visit_class(&mut self, class: &Class) {
+        self.functions += 1;
+        class.visit_children_with(self);
+        self.functions -= 1;
+    }
+
+    fn visit_constructor(&mut self, constructor: &Constructor) {
+        self.functions += 1;
+        constructor.visit_children_with(self);
+        self.functions -= 1;
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.functions += 1;
+        function.visit_children_with(self);
+        self.functions -= 1;
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        let id = ident.to_id();
+        if self.bound.contains(&id) {
+            return;
+        }
+        if self.functions == 0 && ident.sym == "arguments" {
+            self.invalid = true;
+        }
+        if self.seen.insert(id) {
+            self.refs.push(Captured::Ident(ident.clone()));
+        }
+    }
+
+    fn visit_jsx_element(&mut self, _: &JSXElement) {
+        self.invalid = true;
+    }
+
+    fn visit_jsx_fragment(&mut self, _: &JSXFragment) {
+        self.invalid = true;
+    }
+
+    fn visit_meta_prop_expr(&mut self, _: &MetaPropExpr) {
+        if self.functions == 0 {
+            self.invalid = true;
+        }
+    }
+
+    fn visit_super(&mut self, _: &Super) {
+        if self.functions == 0 {
+            self.invalid = true;
+        }
+    }
+
+    fn visit_this_expr(&mut self, _: &ThisExpr) {
+        if self.functions == 0 && !self.refs.iter().any(|r| matches!(r, Captured::This)) {
+            self.refs.push(Captured::This);
+        }
+    }
+}
+
+#[derive(Default)]
+struct AllBindings(FxHashSet<Id>);
+
+impl Visit for AllBindings {
+    noop_visit_type!();
+
+    fn visit_binding_ident(&mut self, ident: &BindingIdent) {
+        self.0.insert(ident.to_id());
+    }
+
+    fn visit_class_decl(&mut self, decl: &ClassDecl) {
+        self.0.insert(decl.ident.to_id());
+        decl.class.visit_with(self);
+    }
+
+    fn visit_class_expr(&mut self, expr: &ClassExpr) {
+        if let Some(ident) = &expr.ident {
+            self.0.insert(ident.to_id());
+        }
+        expr.class.visit_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, decl: &FnDecl) {
+        self.0.insert(decl.ident.to_id());
+        decl.function.visit_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, expr: &FnExpr) {
+        if let Some(ident) = &expr.ident {
+            self.0.insert(ident.to_id());
+        }
+        expr.function.visit_with(self);
+    }
+}
+
+/// Bindings which are assigned after they are declared
+#[derive(Default)]
+pub(super) struct Reassigned(FxHashSet<Id>);
+
+impl Visit for Reassigned {
+    noop_visit_type!();
+
+    fn visit_simple_assign_target(&mut self, target: &SimpleAssignTarget) {
+        if let SimpleAssignTarget::Ident(ident) = target {
+            self.0.insert(ident.to_id());
+        }
+
+        target.visit_children_with(self);
+    }
+
+    fn visit_assign_target_pat(&mut self, pat: &AssignTargetPat) {
+        let mut bindings = AllBindings::default();
+        pat.visit_with(&mut bindings);
+        self.0.extend(bindings.0);
+    }
+
+    fn visit_update_expr(&mut self, expr: &UpdateExpr) {
+        if let Expr::Ident(ident) = &*expr.arg {
+            self.0.insert(ident.to_id());
+        }
+
+        expr.visit_children_with(self);
+    }
+}
+
+struct ReplaceThis(Ident);
+
+impl VisitMut for ReplaceThis {
+    noop_visit_mut_type!();
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+
+    fn visit_mut_constructor(&mut self, _: &mut Constructor) {}
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if let Expr::This(..) = expr {
+            *expr = Expr::Ident(self.0.clone());
+            return;
+        }
+
+        expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+}
@@ -8,6 +8,7 @@ use crate::{
     inferno_flags::{ChildFlags, VNodeFlags},
     refresh::options::{RefreshOptions, deserialize_refresh},
 };
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use swc_atoms::Wtf8Atom;
@@ -15,13 +16,18 @@ use swc_config::merge::Merge;
 use swc_core::atoms::atom;
 use swc_core::common::comments::Comments;
 use swc_core::common::util::take::Take;
-use swc_core::common::{DUMMY_SP, FileName, Mark, SourceMap, Span, Spanned, SyntaxContext};
+use swc_core::common::{
+    BytePos, DUMMY_SP, FileName, Mark, SourceMap, Span, Spanned, SyntaxContext,
+};
 use swc_core::ecma::ast::*;
 use swc_core::ecma::atoms::Atom;
 use swc_core::ecma::utils::{
-    ExprFactory, StmtLike, drop_span, prepend_stmt, quote_ident, swc_atoms,
+    ExprFactory, StmtLike, drop_span, prepend_stmt, prepend_stmts, private_ident, quote_ident,
+    swc_atoms,
+};
+use swc_core::ecma::visit::{
+    Visit, VisitMut, VisitMutWith, VisitWith, noop_visit_mut_type, noop_visit_type, visit_mut_pass,
 };
-use swc_core::ecma::visit::{VisitMut, VisitMutWith, noop_visit_mut_type, visit_mut_pass};
 use swc_core::plugin::errors::HANDLER;
 use swc_ecma_parser::{Syntax, parse_file_as_expr};
 
@@ -29,13 +35,44 @@ use swc_ecma_parser::{Syntax, parse_file_as_expr};
 mod tests;
 
 mod attr;
+mod clone_template;
+mod control;
+mod create_element;
+mod default_props;
+mod form;
+mod hyperscript;
+mod inline_components;
+mod inline_helpers;
+mod link_event;
+mod ssr;
+mod template;
 mod text;
+mod types;
+mod unused_imports;
 mod vnode_args;
 
-use self::attr::{jsx_attr_value_to_expr, jsx_attr_value_to_expr_or_invalid};
-use self::text::jsx_text_to_str;
+use self::attr::{
+    is_normalized_prop, jsx_attr_value_to_expr, jsx_attr_value_to_expr_or_invalid, prop_value,
+    sets_prototype, static_prop_key, take_marker_attr,
+};
+use self::clone_template::TemplateHelper;
+use self::control::ControlStatement;
+use self::create_element::CREATE_ELEMENT_SOURCE;
+use self::default_props::{DefaultProps, collect_default_props, merge_default_props};
+use self::form::check_select_options;
+use self::hyperscript::HYPERSCRIPT_SOURCE;
+use self::inline_components::InlineComponent;
+use self::ssr::SsrHelper;
+use self::template::parse_html_template;
+use self::text::{jsx_text_to_str, preserve_element_whitespace, preserves_whitespace};
+use self::types::{ChildShape, TypeScope};
+use self::unused_imports::{JsxOnlyRefs, remove_unused_imports};
 use self::vnode_args::{CreateVNodeArgs, create_component_vnode_args, create_fragment_vnode_args};
 
+/// Sources of the React compatibility layer, `react` is usually aliased to
+/// `inferno-compat` by the bundler
+const COMPAT_SOURCES: [&str; 2] = ["inferno-compat", "react"];
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq, Merge)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
@@ -54,6 +91,63 @@ pub struct Options {
     #[serde(default, deserialize_with = "deserialize_refresh")]
     // default to disabled since this is still considered as experimental by now
     pub refresh: Option<RefreshOptions>,
+
+    /// Use local TypeScript annotations to infer the `ChildFlags` of expression
+    /// children, e.g. `{props.title}` with `title: string` becomes a text child.
+    #[serde(default)]
+    pub type_aware_child_flags: Option<bool>,
+
+    /// Compile `<If>`, `<Choose>`/`<When>`/`<Otherwise>` and `<For>` to
+    /// conditional expressions and `map` calls.
+    #[serde(default)]
+    pub control_statements: Option<bool>,
+
+    /// Compile `createElement` calls imported from `inferno-create-element`
+    /// like the equivalent JSX.
+    #[serde(default)]
+    pub compile_create_element: Option<bool>,
+
+    /// Compile `h` calls imported from `inferno-hyperscript`, parsing static
+    /// selectors like `div.card#main` at compile time.
+    #[serde(default)]
+    pub compile_hyperscript: Option<bool>,
+
+    /// Names of htm style tags whose tagged templates, like
+    /// `` html`<div>${x}</div>` ``, are compiled like the equivalent JSX.
+    #[serde(default)]
+    pub html_template_tags: Option<Vec<String>>,
+
+    /// Compile elements to concatenated HTML strings for server side
+    /// rendering, which the client can hydrate. vNodes which were not compiled
+    /// this way can not be rendered.
+    #[serde(default)]
+    pub ssr: Option<bool>,
+
+    /// Experimental: compile the children of elements which are mostly static
+    /// to an HTML template which is cloned and patched at runtime.
+    #[serde(default)]
+    pub template_cloning: Option<bool>,
+
+    /// Inline functional components of the same module which only return JSX
+    /// into the elements which render them.
+    #[serde(default)]
+    pub inline_components: Option<bool>,
+
+    /// Merge static `defaultProps` of components declared in the same module
+    /// into the props of the elements which render them.
+    #[serde(default)]
+    pub merge_default_props: Option<bool>,
+
+    /// Rewrite inline arrow event handlers of elements which close over a
+    /// single value to `linkEvent` with a hoisted handler.
+    #[serde(default)]
+    pub link_events: Option<bool>,
+
+    /// Pass every argument to `createVNode`, `createComponentVNode` and
+    /// `createFragment` instead of dropping trailing defaults, so each call
+    /// has the same shape.
+    #[serde(default)]
+    pub define_all_arguments: Option<bool>,
 }
 
 pub fn default_import_source() -> String {
@@ -110,7 +204,7 @@ fn named_import_exists(import_name: &Ident, import: &ImportDecl) -> bool {
     import.specifiers.iter().any(|specifier| {
         matches!(
             specifier,
-            ImportSpecifier::Named(named) if import_name.sym == named.local.sym
+            ImportSpecifier::Named(named) if import_name.to_id() == named.local.to_id()
         )
     })
 }
@@ -153,6 +247,213 @@ fn merge_imports(
     false
 }
 
+/// Extends the first `const { .. } = require(src)` with the missing helpers
+fn merge_requires<T>(
+    imports: &[Ident],
+    src: &Wtf8Atom,
+    unresolved_mark: Mark,
+    stmts: &mut [T],
+    before: BytePos,
+) -> bool
+where
+    T: StmtLike,
+{
+    for stmt in stmts {
+        let Some(Stmt::Decl(Decl::Var(var))) = stmt.as_stmt_mut() else {
+            continue;
+        };
+
+        for declarator in &mut var.decls {
+            if declarator.span.lo >= before {
+                return false;
+            }
+
+            let Pat::Object(ObjectPat { props, .. }) = &mut declarator.name else {
+                continue;
+            };
+
+            // New properties can not be added after a rest element without
+            // changing what it collects
+            if props
+                .iter()
+                .any(|prop| matches!(prop, ObjectPatProp::Rest(..)))
+            {
+                continue;
+            }
+
+            if !matches!(
+                declarator.init.as_deref().and_then(|init| require_binding(
+                    init,
+                    src,
+                    unresolved_mark
+                )),
+                Some(RequireBinding::Namespace)
+            ) {
+                continue;
+            }
+
+            for import_to_add in imports {
+                props.push(ObjectPatProp::Assign(AssignPatProp {
+                    span: DUMMY_SP,
+                    key: import_to_add.clone().into(),
+                    value: None,
+                }));
+            }
+
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Start of the first JSX which is compiled. A `require` after it is not
+/// initialized yet when that JSX renders while the module is evaluated.
+struct FirstJsx<'a> {
+    html_template_tags: &'a [String],
+    pos: BytePos,
+}
+
+impl FirstJsx<'_> {
+    fn find<N>(node: &N, html_template_tags: &[String]) -> BytePos
+    where
+        N: for<'a> VisitWith<FirstJsx<'a>>,
+    {
+        let mut finder = FirstJsx {
+            html_template_tags,
+            pos: BytePos(u32::MAX),
+        };
+        node.visit_with(&mut finder);
+        finder.pos
+    }
+}
+
+impl Visit for FirstJsx<'_> {
+    noop_visit_type!();
+
+    fn visit_jsx_element(&mut self, el: &JSXElement) {
+        self.pos = self.pos.min(el.span.lo);
+    }
+
+    fn visit_jsx_fragment(&mut self, frag: &JSXFragment) {
+        self.pos = self.pos.min(frag.span.lo);
+    }
+
+    fn visit_tagged_tpl(&mut self, tagged: &TaggedTpl) {
+        if let Expr::Ident(tag) = &*tagged.tag
+            && self.html_template_tags.iter().any(|name| tag.sym == **name)
+        {
+            self.pos = self.pos.min(tagged.span.lo);
+            return;
+        }
+
+        tagged.visit_children_with(self);
+    }
+}
+
+/// How the binding of a `require` call exposes the exports of the module
+enum RequireBinding {
+    /// `require("inferno")` or `_interop_require_wildcard(require("inferno"))`
+    Namespace,
+    /// `_interop_require_default(require("inferno"))`, exports live on `.default`
+    Default,
+}
+
+fn require_binding(init: &Expr, src: &Wtf8Atom, unresolved_mark: Mark) -> Option<RequireBinding> {
+    let Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..
+    }) = init.unwrap_parens()
+    else {
+        return None;
+    };
+
+    let [ExprOrSpread { spread: None, expr }] = &args[..] else {
+        return None;
+    };
+
+    match &**callee {
+        Expr::Ident(require)
+            if require.sym == "require" && require.ctxt.outer() == unresolved_mark =>
+        {
+            match &**expr {
+                Expr::Lit(Lit::Str(Str { value, .. })) if value == src => {
+                    Some(RequireBinding::Namespace)
+                }
+                _ => None,
+            }
+        }
+        // _interop_require_default(require("inferno")) or, with external helpers,
+        // _interop_require_default._(require("inferno"))
+        Expr::Ident(..) | Expr::Member(..) => {
+            let helper = match &**callee {
+                Expr::Member(MemberExpr { obj, .. }) => obj.as_ident()?,
+                callee => callee.as_ident()?,
+            };
+
+            if !matches!(
+                require_binding(expr, src, unresolved_mark)?,
+                RequireBinding::Namespace
+            ) {
+                return None;
+            }
+
+            match &*helper.sym {
+                "_interop_require_wildcard" => Some(RequireBinding::Namespace),
+                "_interop_require_default" => Some(RequireBinding::Default),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum RuntimeHelper {
+    CreateVNode,
+    CreateComponentVNode,
+    CreateTextVNode,
+    NormalizeProps,
+    CreateFragment,
+    GetFlagsForElementVnode,
+    CreatePortal,
+    LinkEvent,
+}
+
+impl RuntimeHelper {
+    /// In the order the helpers are added to the import declaration
+    const ALL: [RuntimeHelper; 8] = [
+        RuntimeHelper::CreateVNode,
+        RuntimeHelper::CreateComponentVNode,
+        RuntimeHelper::CreateTextVNode,
+        RuntimeHelper::NormalizeProps,
+        RuntimeHelper::CreateFragment,
+        RuntimeHelper::GetFlagsForElementVnode,
+        RuntimeHelper::CreatePortal,
+        RuntimeHelper::LinkEvent,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            RuntimeHelper::CreateVNode => "createVNode",
+            RuntimeHelper::CreateComponentVNode => "createComponentVNode",
+            RuntimeHelper::CreateTextVNode => "createTextVNode",
+            RuntimeHelper::NormalizeProps => "normalizeProps",
+            RuntimeHelper::CreateFragment => "createFragment",
+            RuntimeHelper::GetFlagsForElementVnode => "getFlagsForElementVnode",
+            RuntimeHelper::CreatePortal => "createPortal",
+            RuntimeHelper::LinkEvent => "linkEvent",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        RuntimeHelper::ALL
+            .into_iter()
+            .find(|helper| helper.name() == name)
+    }
+}
+
 #[derive(PartialEq)]
 pub enum VNodeType {
     Element = 0,
@@ -181,9 +482,43 @@ where
         import_create_text_vnode: None,
         import_create_fragment: None,
         import_normalize_props: None,
+        import_get_flags_for_element_vnode: None,
+        import_create_portal: None,
+        import_link_event: None,
+        local_import_refs: Default::default(),
+        namespace_import: None,
+        fragment_refs: Default::default(),
+        portal_refs: Default::default(),
+        namespace_refs: Default::default(),
+        compat_namespace_refs: Default::default(),
+        first_jsx_pos: BytePos(u32::MAX),
 
         comments,
         top_level_node: true,
+        in_preserved_whitespace: false,
+        type_aware_child_flags: options.type_aware_child_flags.unwrap_or(false),
+        control_statements: options.control_statements.unwrap_or(false),
+        compile_create_element: options.compile_create_element.unwrap_or(false),
+        create_element_refs: Default::default(),
+        create_element_namespace_refs: Default::default(),
+        create_element_lowered: false,
+        compile_hyperscript: options.compile_hyperscript.unwrap_or(false),
+        hyperscript_refs: Default::default(),
+        hyperscript_namespace_refs: Default::default(),
+        hyperscript_lowered: false,
+        html_template_tags: options.html_template_tags.unwrap_or_default(),
+        ssr: options.ssr.unwrap_or(false),
+        ssr_helpers: Default::default(),
+        template_cloning: options.template_cloning.unwrap_or(false),
+        template_helpers: Default::default(),
+        templates: vec![],
+        inline_components: options.inline_components.unwrap_or(false),
+        inlinable_components: Default::default(),
+        merge_default_props: options.merge_default_props.unwrap_or(false),
+        default_props: Default::default(),
+        link_events: options.link_events.unwrap_or(false),
+        define_all_arguments: options.define_all_arguments.unwrap_or(false),
+        types: None,
     })
 }
 
@@ -200,9 +535,72 @@ where
     import_create_text_vnode: Option<Ident>,
     import_create_fragment: Option<Ident>,
     import_normalize_props: Option<Ident>,
+    import_get_flags_for_element_vnode: Option<Ident>,
+    import_create_portal: Option<Ident>,
+    import_link_event: Option<Ident>,
+    /// Helpers which are already imported by the module. Every other helper is
+    /// bound to a private identifier, so user code can never shadow it.
+    local_import_refs: FxHashSet<Id>,
+    /// `import * as Inferno from "inferno"`, a default import of `import_source`
+    /// or the object returned by its `require`, used to access helpers which are
+    /// not imported by name
+    namespace_import: Option<Expr>,
+    /// Local bindings of `Fragment` imported from `import_source`
+    fragment_refs: FxHashSet<Id>,
+    /// Local bindings of `Portal` imported from `import_source`
+    portal_refs: FxHashSet<Id>,
+    /// Namespace and default imports of `import_source`
+    namespace_refs: FxHashSet<Id>,
+    /// Namespace and default imports of `inferno-compat`, whose `Fragment` is
+    /// Inferno's
+    compat_namespace_refs: FxHashSet<Id>,
+    /// `require`s of `import_source` from this position on are not reused
+    first_jsx_pos: BytePos,
     top_level_node: bool,
+    /// Inside a `<pre>` or another element whose text was turned into string
+    /// literals
+    in_preserved_whitespace: bool,
 
     comments: Option<C>,
+
+    type_aware_child_flags: bool,
+    types: Option<TypeScope>,
+
+    control_statements: bool,
+
+    compile_create_element: bool,
+    /// Local bindings of `createElement` from `inferno-create-element`
+    create_element_refs: FxHashSet<Id>,
+    /// Namespace imports of `inferno-create-element`
+    create_element_namespace_refs: FxHashSet<Id>,
+    create_element_lowered: bool,
+
+    compile_hyperscript: bool,
+    /// Local bindings of `h` from `inferno-hyperscript`
+    hyperscript_refs: FxHashSet<Id>,
+    /// Namespace imports of `inferno-hyperscript`
+    hyperscript_namespace_refs: FxHashSet<Id>,
+    hyperscript_lowered: bool,
+
+    html_template_tags: Vec<String>,
+
+    ssr: bool,
+    ssr_helpers: [Option<Ident>; SsrHelper::ALL.len()],
+
+    template_cloning: bool,
+    template_helpers: [Option<Ident>; TemplateHelper::ALL.len()],
+    /// Templates hoisted to the top of the module
+    templates: Vec<(Ident, Expr)>,
+
+    inline_components: bool,
+    inlinable_components: FxHashMap<Id, InlineComponent>,
+
+    merge_default_props: bool,
+    default_props: FxHashMap<Id, DefaultProps>,
+
+    link_events: bool,
+
+    define_all_arguments: bool,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -219,22 +617,18 @@ where
         T: StmtLike,
         F: Fn(Vec<Ident>, Wtf8Atom, &mut Vec<T>),
     {
-        let mut import_specifiers: Vec<Ident> = Vec::with_capacity(5);
+        let mut import_specifiers: Vec<Ident> = Vec::with_capacity(RuntimeHelper::ALL.len());
 
-        if let Some(_local) = self.import_create_vnode.take() {
-            import_specifiers.push(quote_ident!("createVNode").into())
-        }
-        if let Some(_local) = self.import_create_component.take() {
-            import_specifiers.push(quote_ident!("createComponentVNode").into())
-        }
-        if let Some(_local) = self.import_create_text_vnode.take() {
-            import_specifiers.push(quote_ident!("createTextVNode").into())
-        }
-        if let Some(_local) = self.import_normalize_props.take() {
-            import_specifiers.push(quote_ident!("normalizeProps").into())
-        }
-        if let Some(_local) = self.import_create_fragment.take() {
-            import_specifiers.push(quote_ident!("createFragment").into())
+        for helper in RuntimeHelper::ALL {
+            let Some(local) = self.helper_slot(helper).take() else {
+                continue;
+            };
+
+            // Helpers which were already imported by the user are reused as-is and
+            // the rest are accessed through an existing namespace import if there is one
+            if !self.local_import_refs.contains(&local.to_id()) && self.namespace_import.is_none() {
+                import_specifiers.push(local);
+            }
         }
 
         if !import_specifiers.is_empty() {
@@ -244,56 +638,219 @@ where
 
     fn set_local_import_refs(&mut self, stmts: &mut Vec<ModuleItem>) {
         for stmt in stmts {
+            // `import type` bindings are erased, they can not be called at runtime
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                type_only: true, ..
+            })) = stmt
+            {
+                continue;
+            }
+
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = stmt
+                && import.src.value == CREATE_ELEMENT_SOURCE
+            {
+                for specifier in &import.specifiers {
+                    match specifier {
+                        ImportSpecifier::Named(named_import)
+                            if !named_import.is_type_only
+                                && imported_name(named_import) == "createElement" =>
+                        {
+                            self.create_element_refs.insert(named_import.local.to_id());
+                        }
+                        ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                            self.create_element_namespace_refs.insert(local.to_id());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = stmt
+                && import.src.value == HYPERSCRIPT_SOURCE
+            {
+                for specifier in &import.specifiers {
+                    match specifier {
+                        ImportSpecifier::Named(named_import)
+                            if !named_import.is_type_only && imported_name(named_import) == "h" =>
+                        {
+                            self.hyperscript_refs.insert(named_import.local.to_id());
+                        }
+                        ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                            self.hyperscript_namespace_refs.insert(local.to_id());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = stmt
+                && COMPAT_SOURCES.iter().any(|src| import.src.value == *src)
+            {
+                for specifier in &import.specifiers {
+                    match specifier {
+                        ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })
+                        | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                            self.compat_namespace_refs.insert(local.to_id());
+                        }
+                        ImportSpecifier::Named(named_import)
+                            if !named_import.is_type_only
+                                && imported_name(named_import) == "Fragment" =>
+                        {
+                            self.fragment_refs.insert(named_import.local.to_id());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
             if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = stmt
                 && import.src.value == self.import_source
             {
                 for specifier in &import.specifiers {
                     match specifier {
-                        ImportSpecifier::Named(named_import) => {
-                            if named_import.local.sym == "createVNode" {
-                                self.import_create_vnode
-                                    .get_or_insert(named_import.local.clone());
-                            } else if named_import.local.sym == "createComponentVNode" {
-                                self.import_create_component
-                                    .get_or_insert(named_import.local.clone());
-                            } else if named_import.local.sym == "createTextVNode" {
-                                self.import_create_text_vnode
-                                    .get_or_insert(named_import.local.clone());
-                            } else if named_import.local.sym == "createFragment" {
-                                self.import_create_fragment
-                                    .get_or_insert(named_import.local.clone());
-                            } else if named_import.local.sym == "normalizeProps" {
-                                self.import_normalize_props
-                                    .get_or_insert(named_import.local.clone());
-                            }
+                        ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })
+                        | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                            self.add_namespace_ref(local);
                         }
+                        ImportSpecifier::Named(named_import) if !named_import.is_type_only => {
+                            self.add_named_ref(&imported_name(named_import), &named_import.local);
+                        }
+                        ImportSpecifier::Named(..) => {}
+                        #[cfg(swc_ast_unknown)]
                         _ => continue,
                     }
                 }
+            }
+        }
+    }
 
-                return;
+    /// Picks up top level `require` calls of `import_source`, including the
+    /// `_interop_require_*` wrappers emitted by the CommonJS transform, which
+    /// are evaluated before the first JSX
+    fn set_local_require_refs<T>(&mut self, stmts: &[T])
+    where
+        T: StmtLike,
+    {
+        for stmt in stmts {
+            let Some(Stmt::Decl(Decl::Var(var))) = stmt.as_stmt() else {
+                continue;
+            };
+
+            for declarator in &var.decls {
+                if declarator.span.lo >= self.first_jsx_pos {
+                    return;
+                }
+
+                let Some(binding) = declarator.init.as_deref().and_then(|init| {
+                    require_binding(init, &self.import_source, self.unresolved_mark)
+                }) else {
+                    continue;
+                };
+
+                match (&declarator.name, binding) {
+                    (Pat::Ident(BindingIdent { id, .. }), RequireBinding::Namespace) => {
+                        self.add_namespace_ref(id);
+                    }
+                    (Pat::Ident(BindingIdent { id, .. }), RequireBinding::Default) => {
+                        self.namespace_import.get_or_insert_with(|| {
+                            id.clone()
+                                .make_member(IdentName::new(atom!("default"), DUMMY_SP))
+                                .into()
+                        });
+                    }
+                    (Pat::Object(ObjectPat { props, .. }), RequireBinding::Namespace) => {
+                        for prop in props {
+                            match prop {
+                                ObjectPatProp::Assign(AssignPatProp {
+                                    key, value: None, ..
+                                }) => self.add_named_ref(&key.sym, key),
+                                ObjectPatProp::KeyValue(KeyValuePatProp {
+                                    key: PropName::Ident(key),
+                                    value,
+                                }) => {
+                                    if let Pat::Ident(BindingIdent { id, .. }) = &**value {
+                                        self.add_named_ref(&key.sym, id);
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                }
             }
         }
     }
 
+    fn add_namespace_ref(&mut self, local: &Ident) {
+        self.namespace_refs.insert(local.to_id());
+        self.namespace_import
+            .get_or_insert_with(|| Expr::Ident(local.clone()));
+    }
+
+    /// `imported` is the exported name, `local` the binding it is available as
+    fn add_named_ref(&mut self, imported: &str, local: &Ident) {
+        if imported == "Fragment" {
+            self.fragment_refs.insert(local.to_id());
+            return;
+        }
+
+        if imported == "Portal" {
+            self.portal_refs.insert(local.to_id());
+            return;
+        }
+
+        let Some(helper) = RuntimeHelper::from_name(imported) else {
+            return;
+        };
+
+        let import_ref = self.helper_slot(helper);
+        if import_ref.is_none() {
+            *import_ref = Some(local.clone());
+            self.local_import_refs.insert(local.to_id());
+        }
+    }
+
     fn jsx_frag_to_expr(&mut self, el: JSXFragment) -> Expr {
+        if self.ssr {
+            return self.ssr_frag_to_expr(el);
+        }
+
         let span = el.span();
+        let mut el_children = self.flatten_fragments(el.children);
+
+        // <></> => null, <><div/></> => <div/>
+        let mut significant_children = el_children.iter().filter(|child| !is_empty_child(child));
+        match (significant_children.next(), significant_children.next()) {
+            (None, _) => return Expr::Lit(Lit::Null(Null { span })),
+            (Some(JSXElementChild::JSXElement(_)), None) => {
+                if let Some(JSXElementChild::JSXElement(only_child)) =
+                    el_children.drain(..).find(|child| !is_empty_child(child))
+                {
+                    return self.jsx_elem_to_expr(*only_child);
+                }
+            }
+            _ => {}
+        }
 
         if let Some(comments) = &self.comments {
             comments.add_pure_comment(span.lo);
         }
 
-        let fragment = self
-            .import_create_fragment
-            .get_or_insert_with(|| quote_ident!("createFragment").into())
-            .clone();
+        let fragment = self.helper_callee(RuntimeHelper::CreateFragment);
 
         let mut children_requires_normalization: bool = false;
         let mut parent_can_be_keyed: bool = false;
         let mut children_count: u16 = 0;
+        let only_child = el_children
+            .iter()
+            .filter(|child| !is_empty_child(child))
+            .count()
+            == 1;
+        let mut has_spread_vnodes = false;
 
         let mut children = vec![];
-        for child in el.children {
+        for child in el_children {
             let child_expr = Some(match child {
                 JSXElementChild::JSXText(text) => {
                     // TODO(kdy1): Optimize
@@ -312,11 +869,7 @@ where
                         spread: None,
                         expr: Box::new(Expr::Call(CallExpr {
                             span: DUMMY_SP,
-                            callee: self
-                                .import_create_text_vnode
-                                .get_or_insert_with(|| quote_ident!("createTextVNode").into())
-                                .clone()
-                                .as_callee(),
+                            callee: self.helper_callee(RuntimeHelper::CreateTextVNode),
                             args: vec![s.as_arg()],
                             ..Default::default()
                         })),
@@ -325,17 +878,25 @@ where
                 JSXElementChild::JSXExprContainer(JSXExprContainer {
                     expr: JSXExpr::Expr(e),
                     ..
-                }) => {
-                    children_requires_normalization = true;
-                    parent_can_be_keyed = false;
-                    e.as_arg()
-                }
+                }) => match self.expr_child_shape(&e) {
+                    Some(ChildShape::Text) => self.create_text_vnode_call(e.as_arg()).as_arg(),
+                    Some(ChildShape::VNode) => e.as_arg(),
+                    None => {
+                        children_requires_normalization = true;
+                        parent_can_be_keyed = false;
+                        e.as_arg()
+                    }
+                },
                 JSXElementChild::JSXExprContainer(JSXExprContainer {
                     expr: JSXExpr::JSXEmptyExpr(..),
                     ..
                 }) => continue,
                 JSXElementChild::JSXElement(el) => {
-                    if !parent_can_be_keyed && !children_requires_normalization {
+                    if self.control_statement(&el.opening.name).is_some() {
+                        // Conditionals may be null and lists nest arrays
+                        children_requires_normalization = true;
+                        parent_can_be_keyed = false;
+                    } else if !parent_can_be_keyed && !children_requires_normalization {
                         // Loop direct children to check if they have key property set
                         parent_can_be_keyed = Self::does_children_have_key_defined(&el);
                     }
@@ -343,6 +904,17 @@ where
                 }
                 JSXElementChild::JSXFragment(el) => self.jsx_frag_to_expr(el).as_arg(),
                 JSXElementChild::JSXSpreadChild(JSXSpreadChild { span, expr, .. }) => {
+                    match self.spread_child_keyed(&expr) {
+                        Some(keyed) => {
+                            parent_can_be_keyed = keyed && only_child;
+                            has_spread_vnodes = true;
+                        }
+                        None => {
+                            children_requires_normalization = true;
+                            parent_can_be_keyed = false;
+                        }
+                    }
+
                     ExprOrSpread {
                         spread: Some(span),
                         expr,
@@ -355,6 +927,12 @@ where
             children.push(child_expr)
         }
 
+        // Keys of the spread vNodes are not checked against those of the
+        // other children
+        if has_spread_vnodes && !only_child {
+            parent_can_be_keyed = false;
+        }
+
         let child_flags;
 
         if !children_requires_normalization {
@@ -373,14 +951,75 @@ where
 
         Expr::Call(CallExpr {
             span,
-            callee: fragment.as_callee(),
-            args: create_fragment_vnode_args(children, false, child_flags as u16, None, None),
+            callee: fragment,
+            args: create_fragment_vnode_args(
+                children,
+                false,
+                child_flags as u16,
+                None,
+                None,
+                self.define_all_arguments,
+            ),
             type_args: None,
             ..Default::default()
         })
     }
 
-    fn jsx_elem_to_expr(&mut self, el: JSXElement) -> Expr {
+    fn jsx_elem_to_expr(&mut self, mut el: JSXElement) -> Expr {
+        let in_preserved_whitespace = self.in_preserved_whitespace;
+        self.in_preserved_whitespace |= preserve_element_whitespace(&mut el);
+        let expr = self.jsx_elem_to_vnode(el);
+        self.in_preserved_whitespace = in_preserved_whitespace;
+
+        expr
+    }
+
+    fn jsx_elem_to_vnode(&mut self, mut el: JSXElement) -> Expr {
+        if let JSXElementName::Ident(name) = &el.opening.name {
+            match &*name.sym {
+                "textarea" => self.textarea_children_to_default_value(&mut el),
+                "select" => check_select_options(&el),
+                _ => {}
+            }
+        }
+
+        if self.is_keyless_fragment(&el) {
+            return self.jsx_frag_to_expr(JSXFragment {
+                span: el.span,
+                opening: JSXOpeningFragment {
+                    span: el.opening.span,
+                },
+                children: el.children,
+                closing: JSXClosingFragment {
+                    span: el.closing.map_or(DUMMY_SP, |closing| closing.span),
+                },
+            });
+        }
+
+        if let Some(control) = self.control_statement(&el.opening.name) {
+            return self.jsx_control_to_expr(el, control);
+        }
+
+        // <Label text="a" /> => <span className="label">{"a"}</span>
+        if let Some(body) = self.inline_component(&el) {
+            return match body {
+                Expr::JSXFragment(frag) => self.jsx_frag_to_expr(frag),
+                Expr::JSXElement(el) => self.jsx_elem_to_expr(*el),
+                body => body,
+            };
+        }
+
+        if self.ssr {
+            return self.ssr_elem_to_expr(el);
+        }
+
+        if self.is_portal_name(&el.opening.name) {
+            return self.jsx_portal_to_expr(el);
+        }
+
+        self.clone_template(&mut el);
+        let default_props = self.default_props_of(&el);
+
         let top_level_node = self.top_level_node;
         let span = el.span();
         self.top_level_node = false;
@@ -395,27 +1034,58 @@ where
         let mut mut_flags: u16;
         let vnode_kind: VNodeType;
 
+        let is_fragment = self.is_fragment_name(&el.opening.name);
+        // <Tag $Tag /> where `Tag` holds a tag name like "div" rather than a component
+        let dynamic_tag_marker = if is_fragment {
+            None
+        } else {
+            take_marker_attr(&mut el.opening.attrs, "$Tag")
+        };
+        let dynamic_tag = dynamic_tag_marker.is_some();
+        let mut dynamic_tag_expr = None;
+
         match el.opening.name {
+            _ if is_fragment => {
+                vnode_kind = VNodeType::Fragment;
+                mut_flags = VNodeFlags::ComponentUnknown as u16;
+                name_expr = Expr::Ident(Ident::new(
+                    "createFragment".into(),
+                    name_span,
+                    Default::default(),
+                ));
+            }
             JSXElementName::Ident(ident) => {
                 if ident.sym == "this" {
                     vnode_kind = Component;
                     mut_flags = VNodeFlags::ComponentUnknown as u16;
                     name_expr = Expr::This(ThisExpr { span: name_span });
+                } else if dynamic_tag
+                    // JSX reads lowercase names as tag names, only the ones
+                    // interpolated into html templates, whose marker has no
+                    // span, refer to a binding
+                    && (is_component_vnode(&ident)
+                        || dynamic_tag_marker.is_some_and(|marker| marker.is_dummy()))
+                {
+                    vnode_kind = VNodeType::Element;
+                    mut_flags = 0;
+                    name_expr = Expr::Ident(ident);
+                    dynamic_tag_expr = Some(Box::new(name_expr.clone()));
                 } else if is_component_vnode(&ident) {
-                    if ident.sym == "Fragment" {
-                        vnode_kind = VNodeType::Fragment;
-                        mut_flags = VNodeFlags::ComponentUnknown as u16;
-                        name_expr = Expr::Ident(Ident::new(
-                            "createFragment".into(),
-                            ident.span,
-                            Default::default(),
-                        ));
-                    } else {
-                        vnode_kind = Component;
-                        mut_flags = VNodeFlags::ComponentUnknown as u16;
-                        name_expr = Expr::Ident(ident)
-                    }
+                    vnode_kind = Component;
+                    mut_flags = VNodeFlags::ComponentUnknown as u16;
+                    name_expr = Expr::Ident(ident)
                 } else {
+                    if let Some(marker) = dynamic_tag_marker {
+                        HANDLER.with(|handler| {
+                            handler
+                                .struct_span_err(
+                                    marker,
+                                    "$Tag needs a binding, lowercase JSX names are tag names",
+                                )
+                                .emit()
+                        });
+                    }
+
                     vnode_kind = VNodeType::Element;
                     mut_flags = parse_vnode_flag(&ident.sym);
                     name_expr = Expr::Lit(Lit::Str(Str {
@@ -435,8 +1105,13 @@ where
                 return Expr::Invalid(Invalid { span: DUMMY_SP });
             }
             JSXElementName::JSXMemberExpr(JSXMemberExpr { obj, prop, .. }) => {
-                vnode_kind = Component;
-                mut_flags = VNodeFlags::ComponentUnknown as u16;
+                if dynamic_tag {
+                    vnode_kind = VNodeType::Element;
+                    mut_flags = 0;
+                } else {
+                    vnode_kind = Component;
+                    mut_flags = VNodeFlags::ComponentUnknown as u16;
+                }
 
                 fn convert_obj(obj: JSXObject) -> Box<Expr> {
                     let span = obj.span();
@@ -461,7 +1136,10 @@ where
                     span: name_span,
                     obj: convert_obj(obj),
                     prop: MemberProp::Ident(prop.clone()),
-                })
+                });
+                if dynamic_tag {
+                    dynamic_tag_expr = Some(Box::new(name_expr.clone()));
+                }
             }
         }
 
@@ -486,7 +1164,17 @@ where
         let mut content_editable_props: bool = false;
         let mut prop_children: Option<Box<Expr>> = None;
 
-        for attr in el.opening.attrs {
+        // normalizeProps only applies `props.className` when the vNode has none,
+        // so a className hoisted before an unknown spread would win over it
+        let last_unknown_spread = el.opening.attrs.iter().rposition(|attr| {
+            matches!(attr, JSXAttrOrSpread::SpreadElement(spread) if !matches!(
+                &*spread.expr,
+                Expr::Object(obj) if !sets_prototype(obj)
+                    && obj.props.iter().all(|prop| static_prop_key(prop).is_some())
+            ))
+        });
+
+        for (index, attr) in el.opening.attrs.into_iter().enumerate() {
             match attr {
                 JSXAttrOrSpread::JSXAttr(attr) => {
                     //
@@ -724,9 +1412,48 @@ where
                     }
                 }
                 JSXAttrOrSpread::SpreadElement(attr) => match *attr.expr {
-                    Expr::Object(obj) => {
-                        needs_normalization = true;
-                        props_obj.props.extend(obj.props);
+                    // `__proto__` is only a key of the spread literal itself
+                    Expr::Object(obj) if !sets_prototype(&obj) => {
+                        // Well-known props can only be hoisted out of the literal when all of
+                        // its keys are known, a computed key could override them at runtime
+                        let keys_are_static =
+                            obj.props.iter().all(|prop| static_prop_key(prop).is_some());
+
+                        for prop in obj.props {
+                            let key = static_prop_key(&prop);
+
+                            let prop = match key.as_deref() {
+                                Some(name @ ("key" | "ref" | "className" | "class"))
+                                    if keys_are_static
+                                        && (name == "key"
+                                            || name == "ref"
+                                            || (vnode_kind == VNodeType::Element
+                                                && last_unknown_spread
+                                                    .is_none_or(|last| last < index))) =>
+                                {
+                                    match prop_value(prop) {
+                                        Ok(value) => {
+                                            match name {
+                                                "key" => key_prop = Some(value.as_arg()),
+                                                "ref" => ref_prop = Some(value.as_arg()),
+                                                _ => class_name_param = Some(value),
+                                            }
+                                            continue;
+                                        }
+                                        Err(prop) => prop,
+                                    }
+                                }
+                                _ => prop,
+                            };
+
+                            // Inline the rest as-is unless normalizeProps has to move it
+                            if !key.is_some_and(|key| {
+                                !is_normalized_prop(&key, vnode_kind != Component)
+                            }) {
+                                needs_normalization = true;
+                            }
+                            props_obj.props.push(prop);
+                        }
                     }
                     _ => {
                         needs_normalization = true;
@@ -738,12 +1465,29 @@ where
 
         let mut children_requires_normalization: bool = false;
         let mut children_found_text: bool = false;
+        let mut for_child_keyed: Option<bool> = None;
         let mut parent_can_be_keyed: bool = false;
         let mut children_count: u16 = 0;
+        // Expression children known to be text from type annotations
+        let mut text_expr_children: Vec<usize> = vec![];
 
         let mut children = vec![];
 
-        for child in el.children {
+        // Component children are passed through props as-is, so their shape has to be kept
+        let el_children = if vnode_kind == Component {
+            flatten_spread_children(el.children)
+        } else {
+            self.flatten_fragments(el.children)
+        };
+        let only_child = el_children
+            .iter()
+            .filter(|child| !is_empty_child(child))
+            .count()
+            == 1;
+        // Spread children which are known to be vNodes, next to other children
+        let mut has_spread_vnodes = false;
+
+        for child in el_children {
             let child_expr = Some(match child {
                 JSXElementChild::JSXText(text) => {
                     // TODO(kdy1): Optimize
@@ -764,11 +1508,7 @@ where
                             expr: Box::new(Expr::Call(CallExpr {
                                 span: DUMMY_SP,
                                 ctxt: unresolved_ctxt,
-                                callee: self
-                                    .import_create_text_vnode
-                                    .get_or_insert_with(|| quote_ident!("createTextVNode").into())
-                                    .clone()
-                                    .as_callee(),
+                                callee: self.helper_callee(RuntimeHelper::CreateTextVNode),
                                 args: vec![s.as_arg()],
                                 type_args: Default::default(),
                             })),
@@ -781,17 +1521,42 @@ where
                 JSXElementChild::JSXExprContainer(JSXExprContainer {
                     expr: JSXExpr::Expr(e),
                     ..
-                }) => {
-                    children_requires_normalization = true;
-                    parent_can_be_keyed = false;
-                    e.as_arg()
-                }
+                }) => match self.expr_child_shape(&e) {
+                    Some(ChildShape::Text) if vnode_kind == VNodeType::Fragment => {
+                        self.create_text_vnode_call(e.as_arg()).as_arg()
+                    }
+                    Some(ChildShape::Text) => {
+                        children_found_text = true;
+                        text_expr_children.push(children.len());
+                        e.as_arg()
+                    }
+                    Some(ChildShape::VNode) => e.as_arg(),
+                    None => {
+                        children_requires_normalization = true;
+                        parent_can_be_keyed = false;
+                        e.as_arg()
+                    }
+                },
                 JSXElementChild::JSXExprContainer(JSXExprContainer {
                     expr: JSXExpr::JSXEmptyExpr(..),
                     ..
                 }) => continue,
                 JSXElementChild::JSXElement(el) => {
-                    if vnode_kind != Component
+                    let control = self.control_statement(&el.opening.name);
+                    let for_keyed = match control {
+                        Some(ControlStatement::For) if !self.has_empty_body(&el.children) => {
+                            self.for_child_keyed(&el)
+                        }
+                        _ => None,
+                    };
+                    if let Some(keyed) = for_keyed {
+                        for_child_keyed = Some(keyed);
+                    } else if control.is_some() {
+                        // Conditionals may be null, like the body of a <For>
+                        // which is not a single element
+                        children_requires_normalization = true;
+                        parent_can_be_keyed = false;
+                    } else if vnode_kind != Component
                         && !parent_can_be_keyed
                         && !children_known
                         && !children_requires_normalization
@@ -803,9 +1568,35 @@ where
                 }
                 JSXElementChild::JSXFragment(el) => self.jsx_frag_to_expr(el).as_arg(),
                 JSXElementChild::JSXSpreadChild(JSXSpreadChild { span, expr, .. }) => {
-                    ExprOrSpread {
-                        spread: Some(span),
-                        expr,
+                    match self.spread_child_keyed(&expr) {
+                        // {...items.map(..)} is the children array itself, copied
+                        // as `map` of a collection may not return an array
+                        Some(keyed) if only_child => {
+                            for_child_keyed = Some(keyed);
+                            ArrayLit {
+                                span: DUMMY_SP,
+                                elems: vec![Some(ExprOrSpread {
+                                    spread: Some(span),
+                                    expr,
+                                })],
+                            }
+                            .as_arg()
+                        }
+                        Some(_) => {
+                            has_spread_vnodes = true;
+                            ExprOrSpread {
+                                spread: Some(span),
+                                expr,
+                            }
+                        }
+                        None => {
+                            children_requires_normalization = true;
+                            parent_can_be_keyed = false;
+                            ExprOrSpread {
+                                spread: Some(span),
+                                expr,
+                            }
+                        }
                     }
                 }
             });
@@ -815,11 +1606,29 @@ where
             children.push(child_expr)
         }
 
+        // Keys of the spread vNodes are not checked against those of the
+        // other children
+        if has_spread_vnodes {
+            parent_can_be_keyed = false;
+        }
+
+        // The array of a <For> is the children array itself when it is the only
+        // child, otherwise it is nested and has to be normalized
+        if let Some(keyed) = for_child_keyed {
+            if children_count == 1 && !children_known {
+                has_keyed_children = keyed;
+                has_non_keyed_children = !keyed;
+            } else if children_count > 1 {
+                children_requires_normalization = true;
+                parent_can_be_keyed = false;
+            }
+        }
+
         if children_found_text {
             match children_count {
                 1 => has_text_children = true,
                 _ => {
-                    for child in &mut children {
+                    for (index, child) in children.iter_mut().enumerate() {
                         let Some(expr) = child.take() else {
                             continue;
                         };
@@ -831,17 +1640,13 @@ where
                                 expr: Box::new(Expr::Call(CallExpr {
                                     span: DUMMY_SP,
                                     ctxt: unresolved_ctxt,
-                                    callee: self
-                                        .import_create_text_vnode
-                                        .get_or_insert_with(|| {
-                                            quote_ident!("createTextVNode").into()
-                                        })
-                                        .clone()
-                                        .as_callee(),
+                                    callee: self.helper_callee(RuntimeHelper::CreateTextVNode),
                                     args: vec![text.as_arg()],
                                     type_args: Default::default(),
                                 })),
                             });
+                        } else if text_expr_children.contains(&index) {
+                            *child = Some(self.create_text_vnode_call(expr).as_arg());
                         } else {
                             *child = Some(expr);
                         }
@@ -934,6 +1739,10 @@ where
                         }))));
                 }
             }
+
+            if let Some(defaults) = default_props {
+                merge_default_props(&mut props_obj, defaults);
+            }
         } else {
             // Backwards compatibility...
             // Set prop children as children if no nested children were set
@@ -959,29 +1768,48 @@ where
             mut_flags |= VNodeFlags::ContentEditable as u16;
         }
 
-        let flags_expr = match flags_override_param {
-            None => Box::new(Expr::Lit(Lit::Num(Number {
+        let flags_expr = match (flags_override_param, dynamic_tag_expr) {
+            (None, None) => Box::new(Expr::Lit(Lit::Num(Number {
                 span: DUMMY_SP,
                 raw: None,
                 value: mut_flags as f64,
             })))
             .as_arg(),
-            Some(v) => v,
+            // The element flags of a dynamic tag are only known at runtime
+            (None, Some(tag)) => {
+                let get_flags = Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: self.helper_callee(RuntimeHelper::GetFlagsForElementVnode),
+                    args: vec![tag.as_arg()],
+                    ..Default::default()
+                });
+
+                if mut_flags == 0 {
+                    get_flags.as_arg()
+                } else {
+                    BinExpr {
+                        span: DUMMY_SP,
+                        op: BinaryOp::BitOr,
+                        left: Box::new(get_flags),
+                        right: Box::new(Expr::Lit(Lit::Num(Number {
+                            span: DUMMY_SP,
+                            raw: None,
+                            value: mut_flags as f64,
+                        }))),
+                    }
+                    .as_arg()
+                }
+            }
+            (Some(v), _) => v,
         };
 
-        let create_method = if vnode_kind == Component {
-            self.import_create_component
-                .get_or_insert_with(|| quote_ident!("createComponentVNode").into())
-                .clone()
+        let create_method = self.helper_callee(if vnode_kind == Component {
+            RuntimeHelper::CreateComponentVNode
         } else if vnode_kind == VNodeType::Element {
-            self.import_create_vnode
-                .get_or_insert_with(|| quote_ident!("createVNode").into())
-                .clone()
+            RuntimeHelper::CreateVNode
         } else {
-            self.import_create_fragment
-                .get_or_insert_with(|| quote_ident!("createFragment").into())
-                .clone()
-        };
+            RuntimeHelper::CreateFragment
+        });
 
         let create_method_args = if vnode_kind == Component {
             // Functional component cannot have basic ref so when component refs is set use it
@@ -994,9 +1822,17 @@ where
                     props_obj,
                     key_prop,
                     Some(some_refs.as_arg()),
+                    self.define_all_arguments,
                 )
             } else {
-                create_component_vnode_args(flags_expr, name_expr, props_obj, key_prop, ref_prop)
+                create_component_vnode_args(
+                    flags_expr,
+                    name_expr,
+                    props_obj,
+                    key_prop,
+                    ref_prop,
+                    self.define_all_arguments,
+                )
             }
         } else if vnode_kind == VNodeType::Element {
             CreateVNodeArgs {
@@ -1009,6 +1845,7 @@ where
                 props: props_obj,
                 key: key_prop,
                 refs: ref_prop,
+                define_all_arguments: self.define_all_arguments,
             }
             .into_args()
         } else {
@@ -1020,13 +1857,14 @@ where
                 child_flags as u16,
                 child_flags_override_param,
                 key_prop,
+                self.define_all_arguments,
             )
         };
 
         let create_expr = Expr::Call(CallExpr {
             span,
             ctxt: unresolved_ctxt,
-            callee: create_method.as_callee(),
+            callee: create_method,
             args: create_method_args,
             type_args: Default::default(),
         });
@@ -1035,11 +1873,7 @@ where
             return Expr::Call(CallExpr {
                 span,
                 ctxt: unresolved_ctxt,
-                callee: self
-                    .import_normalize_props
-                    .get_or_insert_with(|| quote_ident!("normalizeProps").into())
-                    .clone()
-                    .as_callee(),
+                callee: self.helper_callee(RuntimeHelper::NormalizeProps),
                 args: vec![create_expr.as_arg()],
                 type_args: Default::default(),
             });
@@ -1048,6 +1882,247 @@ where
         create_expr
     }
 
+    /// `<Fragment>` without a key or any special flags, which behaves exactly
+    /// like `<></>`
+    fn is_keyless_fragment(&self, el: &JSXElement) -> bool {
+        el.opening.attrs.is_empty() && self.is_fragment_name(&el.opening.name)
+    }
+
+    /// Resolves `<Fragment>`, `<F>` (`import { Fragment as F }`) and
+    /// `<Inferno.Fragment>` through the imports of `import_source`, and
+    /// `<React.Fragment>` through those of `inferno-compat`. A global
+    /// `Fragment` is accepted too, but a user defined component with the same
+    /// name is not.
+    fn is_fragment_name(&self, name: &JSXElementName) -> bool {
+        match name {
+            JSXElementName::Ident(ident) => {
+                self.fragment_refs.contains(&ident.to_id())
+                    || (ident.sym == "Fragment" && self.is_unresolved(ident))
+            }
+            JSXElementName::JSXMemberExpr(JSXMemberExpr {
+                obj: JSXObject::Ident(obj),
+                prop,
+                ..
+            }) => {
+                prop.sym == "Fragment"
+                    && (self.namespace_refs.contains(&obj.to_id())
+                        || self.compat_namespace_refs.contains(&obj.to_id())
+                        || (matches!(&*obj.sym, "Inferno" | "React") && self.is_unresolved(obj)))
+            }
+            _ => false,
+        }
+    }
+
+    /// `Portal` is not exported by Inferno, importing it from `import_source` is
+    /// what enables `<Portal container={el}>`
+    fn is_portal_name(&self, name: &JSXElementName) -> bool {
+        match name {
+            JSXElementName::Ident(ident) => self.portal_refs.contains(&ident.to_id()),
+            JSXElementName::JSXMemberExpr(JSXMemberExpr {
+                obj: JSXObject::Ident(obj),
+                prop,
+                ..
+            }) => prop.sym == "Portal" && self.namespace_refs.contains(&obj.to_id()),
+            _ => false,
+        }
+    }
+
+    /// <Portal container={el}>{children}</Portal> => createPortal(children, el)
+    fn jsx_portal_to_expr(&mut self, el: JSXElement) -> Expr {
+        let span = el.span();
+        let mut container = None;
+
+        for attr in el.opening.attrs {
+            match attr {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    value,
+                    ..
+                }) if name.sym == "container" => {
+                    container = value.and_then(jsx_attr_value_to_expr);
+                }
+                attr => {
+                    HANDLER.with(|handler| {
+                        handler
+                            .struct_span_err(
+                                attr.span(),
+                                "Portal only accepts the 'container' property",
+                            )
+                            .emit()
+                    });
+                }
+            }
+        }
+
+        let Some(container) = container else {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(
+                        el.opening.span,
+                        "The value of property 'container' should not be empty",
+                    )
+                    .emit()
+            });
+
+            return Expr::Invalid(Invalid { span });
+        };
+
+        // Multiple children are rendered through a fragment, a single element as is
+        let top_level_node = self.top_level_node;
+        self.top_level_node = false;
+        let children = self.jsx_frag_to_expr(JSXFragment {
+            span,
+            opening: JSXOpeningFragment {
+                span: el.opening.span,
+            },
+            children: el.children,
+            closing: JSXClosingFragment { span: DUMMY_SP },
+        });
+        self.top_level_node = top_level_node;
+
+        if let Some(comments) = &self.comments {
+            comments.add_pure_comment(span.lo);
+        }
+
+        Expr::Call(CallExpr {
+            span,
+            callee: self.helper_callee(RuntimeHelper::CreatePortal),
+            args: vec![children.as_arg(), container.as_arg()],
+            ..Default::default()
+        })
+    }
+
+    fn is_unresolved(&self, ident: &Ident) -> bool {
+        ident.ctxt.outer() == self.unresolved_mark
+    }
+
+    /// Splices the children of nested keyless fragments into their parent
+    fn flatten_fragments(&self, children: Vec<JSXElementChild>) -> Vec<JSXElementChild> {
+        let mut flattened = Vec::with_capacity(children.len());
+
+        for child in flatten_spread_children(children) {
+            match child {
+                JSXElementChild::JSXFragment(frag) => {
+                    flattened.extend(self.flatten_fragments(frag.children))
+                }
+                JSXElementChild::JSXElement(el) if self.is_keyless_fragment(&el) => {
+                    flattened.extend(self.flatten_fragments(el.children))
+                }
+                child => flattened.push(child),
+            }
+        }
+
+        flattened
+    }
+
+    /// `{...items.map((item) => <li key={item.id} />)}` spreads one vNode per
+    /// item, which are keyed when the element has a `key`
+    fn spread_child_keyed(&self, expr: &Expr) -> Option<bool> {
+        let Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) = expr
+        else {
+            return None;
+        };
+        let Expr::Member(MemberExpr {
+            prop: MemberProp::Ident(method),
+            ..
+        }) = &**callee
+        else {
+            return None;
+        };
+        let Some(ExprOrSpread {
+            spread: None,
+            expr: callback,
+        }) = args.first()
+        else {
+            return None;
+        };
+        if method.sym != "map" {
+            return None;
+        }
+
+        let body = match &**callback {
+            Expr::Arrow(ArrowExpr { body, .. }) => match &**body {
+                BlockStmtOrExpr::Expr(expr) => &**expr,
+                BlockStmtOrExpr::BlockStmt(block) => returned_expr(block)?,
+                #[cfg(swc_ast_unknown)]
+                _ => return None,
+            },
+            Expr::Fn(FnExpr { function, .. }) => returned_expr(function.body.as_ref()?)?,
+            _ => return None,
+        };
+
+        let mut body = body;
+        while let Expr::Paren(ParenExpr { expr, .. }) = body {
+            body = expr;
+        }
+
+        // Fragments and conditionals may render nothing or several nodes
+        match body {
+            Expr::JSXElement(el)
+                if !self.is_fragment_name(&el.opening.name)
+                    && self.control_statement(&el.opening.name).is_none() =>
+            {
+                Some(Self::does_children_have_key_defined(el))
+            }
+            _ => None,
+        }
+    }
+
+    fn helper_slot(&mut self, helper: RuntimeHelper) -> &mut Option<Ident> {
+        match helper {
+            RuntimeHelper::CreateVNode => &mut self.import_create_vnode,
+            RuntimeHelper::CreateComponentVNode => &mut self.import_create_component,
+            RuntimeHelper::CreateTextVNode => &mut self.import_create_text_vnode,
+            RuntimeHelper::NormalizeProps => &mut self.import_normalize_props,
+            RuntimeHelper::CreateFragment => &mut self.import_create_fragment,
+            RuntimeHelper::GetFlagsForElementVnode => &mut self.import_get_flags_for_element_vnode,
+            RuntimeHelper::CreatePortal => &mut self.import_create_portal,
+            RuntimeHelper::LinkEvent => &mut self.import_link_event,
+        }
+    }
+
+    /// `createVNode` or `Inferno.createVNode` when the module already has a
+    /// namespace import of `import_source`
+    fn helper_callee(&mut self, helper: RuntimeHelper) -> Callee {
+        let local = self
+            .helper_slot(helper)
+            .get_or_insert_with(|| private_ident!(helper.name()))
+            .clone();
+
+        match &self.namespace_import {
+            Some(namespace) if !self.local_import_refs.contains(&local.to_id()) => namespace
+                .clone()
+                .make_member(IdentName::new(local.sym, DUMMY_SP))
+                .as_callee(),
+            _ => local.as_callee(),
+        }
+    }
+
+    fn expr_child_shape(&self, expr: &Expr) -> Option<ChildShape> {
+        // The text of a <pre>
+        if self.in_preserved_whitespace
+            && let Expr::Lit(Lit::Str(..)) = expr
+        {
+            return Some(ChildShape::Text);
+        }
+
+        self.types.as_ref()?.child_shape(expr)
+    }
+
+    fn create_text_vnode_call(&mut self, text: ExprOrSpread) -> Expr {
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            ctxt: SyntaxContext::empty().apply_mark(self.unresolved_mark),
+            callee: self.helper_callee(RuntimeHelper::CreateTextVNode),
+            args: vec![text],
+            type_args: Default::default(),
+        })
+    }
+
     fn does_children_have_key_defined(el: &JSXElement) -> bool {
         for attr in &el.opening.attrs {
             match attr {
@@ -1082,28 +2157,43 @@ where
 
     fn visit_mut_expr(&mut self, expr: &mut Expr) {
         let top_level_node = self.top_level_node;
+        let in_preserved_whitespace = self.in_preserved_whitespace;
         let mut did_work = false;
 
+        if let Expr::TaggedTpl(tagged) = expr
+            && let Expr::Ident(tag) = &*tagged.tag
+            && self.html_template_tags.iter().any(|name| tag.sym == **name)
+        {
+            // html`<div />` => <div />
+            match parse_html_template(&tagged.tpl) {
+                Ok(jsx) => *expr = jsx,
+                Err((span, msg)) => {
+                    HANDLER.with(|handler| handler.struct_span_err(span, &msg).emit());
+                }
+            }
+        }
+
+        // (<div />) as any => <div />
+        if let Some(jsx) = jsx_in_wrappers(expr) {
+            *expr = jsx.take();
+        }
+
         if let Expr::JSXElement(el) = expr {
             did_work = true;
+            // Like `{items.map((item) => <b> {item} </b>)}` within a <pre>
+            self.in_preserved_whitespace |= preserves_whitespace(el);
             // <div></div> => Inferno.createVNode(...);
             *expr = self.jsx_elem_to_expr(*el.take());
         } else if let Expr::JSXFragment(frag) = expr {
             // <></> => Inferno.createFragment(...);
             did_work = true;
             *expr = self.jsx_frag_to_expr(frag.take());
-        } else if let Expr::Paren(ParenExpr {
-            expr: inner_expr, ..
-        }) = expr
+        } else if let Expr::Call(call) = expr
+            && let Some(el) = self.call_to_jsx(call)
         {
-            if let Expr::JSXElement(el) = &mut **inner_expr {
-                did_work = true;
-                *expr = self.jsx_elem_to_expr(*el.take());
-            } else if let Expr::JSXFragment(frag) = &mut **inner_expr {
-                // <></> => Inferno.createFragment(...);
-                did_work = true;
-                *expr = self.jsx_frag_to_expr(frag.take());
-            }
+            // createElement("div") => Inferno.createVNode(...);
+            did_work = true;
+            *expr = self.jsx_elem_to_expr(el);
         }
 
         if did_work {
@@ -1113,19 +2203,83 @@ where
         expr.visit_mut_children_with(self);
 
         self.top_level_node = top_level_node;
+        self.in_preserved_whitespace = in_preserved_whitespace;
+    }
+
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        if let Some(types) = self.types.as_mut() {
+            types.enter_class(class);
+        }
+        class.visit_mut_children_with(self);
+        if let Some(types) = self.types.as_mut() {
+            types.exit();
+        }
+    }
+
+    fn visit_mut_fn_decl(&mut self, decl: &mut FnDecl) {
+        if let Some(types) = self.types.as_mut() {
+            types.enter_function();
+        }
+        decl.visit_mut_children_with(self);
+        if let Some(types) = self.types.as_mut() {
+            types.exit();
+        }
+    }
+
+    fn visit_mut_fn_expr(&mut self, expr: &mut FnExpr) {
+        if let Some(types) = self.types.as_mut() {
+            types.enter_function();
+        }
+        expr.visit_mut_children_with(self);
+        if let Some(types) = self.types.as_mut() {
+            types.exit();
+        }
     }
 
     fn visit_mut_module(&mut self, module: &mut Module) {
+        if self.type_aware_child_flags {
+            self.types = Some(TypeScope::collect(&*module, &self.import_source));
+        }
+
         self.set_local_import_refs(&mut module.body);
+        self.first_jsx_pos = FirstJsx::find(&*module, &self.html_template_tags);
+        self.set_local_require_refs(&module.body);
+        let jsx_only_refs = JsxOnlyRefs::collect(module);
+        if self.inline_components {
+            self.inlinable_components = self.collect_inline_components(module);
+        }
+        // Server rendering calls components without their defaults
+        if self.merge_default_props && !self.ssr {
+            self.default_props = collect_default_props(module);
+        }
+        // Event handlers are left out of the HTML when rendering on the server
+        if self.link_events && !self.ssr {
+            let handlers = self.hoist_link_events(module);
+            let index = module
+                .body
+                .iter()
+                .position(|item| !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(..))))
+                .unwrap_or(module.body.len());
+            module
+                .body
+                .splice(index..index, handlers.into_iter().map(ModuleItem::Stmt));
+        }
 
         self.visit_mut_module_items(&mut module.body);
 
+        let mark = self.unresolved_mark;
+        let first_jsx_pos = self.first_jsx_pos;
         self.inject_runtime(&mut module.body, |imports, default_import_src, stmts| {
             // Merge new imports to existing import
             if merge_imports(&imports, &default_import_src, stmts) {
                 return;
             }
 
+            // CommonJS output of a previous pass, extend its require instead
+            if merge_requires(&imports, &default_import_src, mark, stmts, first_jsx_pos) {
+                return;
+            }
+
             // Existing inferno import was not found, add new
             let specifiers: Vec<ImportSpecifier> = imports
                 .into_iter()
@@ -1156,6 +2310,35 @@ where
                 })),
             )
         });
+
+        let mut inline_stmts = self.take_ssr_helpers();
+        inline_stmts.extend(self.take_templates());
+        if !inline_stmts.is_empty() {
+            let index = module
+                .body
+                .iter()
+                .position(|item| !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(..))))
+                .unwrap_or(module.body.len());
+            module
+                .body
+                .splice(index..index, inline_stmts.into_iter().map(ModuleItem::Stmt));
+        }
+
+        remove_unused_imports(&mut module.body, &self.import_source, &jsx_only_refs);
+        if self.create_element_lowered {
+            remove_unused_imports(
+                &mut module.body,
+                &CREATE_ELEMENT_SOURCE.into(),
+                &JsxOnlyRefs::compiled_call("createElement"),
+            );
+        }
+        if self.hyperscript_lowered {
+            remove_unused_imports(
+                &mut module.body,
+                &HYPERSCRIPT_SOURCE.into(),
+                &JsxOnlyRefs::compiled_call("h"),
+            );
+        }
     }
 
     fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
@@ -1167,12 +2350,129 @@ where
     }
 
     fn visit_mut_script(&mut self, script: &mut Script) {
+        if self.type_aware_child_flags {
+            self.types = Some(TypeScope::collect(&*script, &self.import_source));
+        }
+
+        self.first_jsx_pos = FirstJsx::find(&*script, &self.html_template_tags);
+        self.set_local_require_refs(&script.body);
+        if self.link_events && !self.ssr {
+            let handlers = self.hoist_link_events(script);
+            prepend_stmts(&mut script.body, handlers.into_iter());
+        }
+
         self.visit_mut_stmts(&mut script.body);
 
         let mark = self.unresolved_mark;
+        let first_jsx_pos = self.first_jsx_pos;
         self.inject_runtime(&mut script.body, |imports, src, stmts| {
+            if merge_requires(&imports, &src, mark, stmts, first_jsx_pos) {
+                return;
+            }
+
             prepend_stmt(stmts, add_require(imports, src, mark))
         });
+
+        let mut inline_stmts = self.take_ssr_helpers();
+        inline_stmts.extend(self.take_templates());
+        prepend_stmts(&mut script.body, inline_stmts.into_iter());
+    }
+}
+
+/// JSX wrapped in parentheses or TypeScript expressions which only exist for
+/// the type checker, like `(<div />) as any`, `<div />!` or `<div /> satisfies T`
+fn jsx_in_wrappers(expr: &mut Expr) -> Option<&mut Expr> {
+    let inner = match expr {
+        Expr::Paren(ParenExpr { expr, .. })
+        | Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+        | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => &mut **expr,
+        _ => return None,
+    };
+
+    match inner {
+        Expr::JSXElement(..) | Expr::JSXFragment(..) => Some(inner),
+        _ => jsx_in_wrappers(inner),
+    }
+}
+
+fn imported_name(named: &ImportNamedSpecifier) -> Atom {
+    match &named.imported {
+        Some(ModuleExportName::Ident(imported)) => imported.sym.clone(),
+        Some(ModuleExportName::Str(s)) => s.value.to_atom_lossy().into_owned(),
+        None => named.local.sym.clone(),
+        #[cfg(swc_ast_unknown)]
+        Some(_) => named.local.sym.clone(),
+    }
+}
+
+/// Splices the elements of array literals spread as children,
+/// `{...[<a />, <b />]}`, into their parent
+fn flatten_spread_children(children: Vec<JSXElementChild>) -> Vec<JSXElementChild> {
+    let mut flattened = Vec::with_capacity(children.len());
+
+    for child in children {
+        let JSXElementChild::JSXSpreadChild(JSXSpreadChild { span, expr }) = child else {
+            flattened.push(child);
+            continue;
+        };
+        let Expr::Array(ArrayLit { elems, .. }) = *expr else {
+            flattened.push(JSXElementChild::JSXSpreadChild(JSXSpreadChild {
+                span,
+                expr,
+            }));
+            continue;
+        };
+
+        // Holes spread as `undefined`
+        if elems.iter().any(Option::is_none) {
+            flattened.push(JSXElementChild::JSXSpreadChild(JSXSpreadChild {
+                span,
+                expr: Box::new(Expr::Array(ArrayLit { span, elems })),
+            }));
+            continue;
+        }
+
+        let elems = elems.into_iter().flatten().map(|elem| match elem {
+            ExprOrSpread {
+                spread: Some(span),
+                expr,
+            } => JSXElementChild::JSXSpreadChild(JSXSpreadChild { span, expr }),
+            ExprOrSpread { expr, .. } => match *expr {
+                Expr::JSXElement(el) => JSXElementChild::JSXElement(el),
+                Expr::JSXFragment(frag) => JSXElementChild::JSXFragment(frag),
+                expr => JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    span: expr.span(),
+                    expr: JSXExpr::Expr(Box::new(expr)),
+                }),
+            },
+        });
+        flattened.extend(flatten_spread_children(elems.collect()));
+    }
+
+    flattened
+}
+
+/// The argument of a body which is a single `return`
+fn returned_expr(body: &BlockStmt) -> Option<&Expr> {
+    match &body.stmts[..] {
+        [Stmt::Return(ReturnStmt { arg: Some(arg), .. })] => Some(arg),
+        _ => None,
+    }
+}
+
+/// Children which do not produce any output, like `{/* comment */}` or
+/// whitespace between lines
+fn is_empty_child(child: &JSXElementChild) -> bool {
+    match child {
+        JSXElementChild::JSXText(text) => jsx_text_to_str(&*text.value).is_empty(),
+        JSXElementChild::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::JSXEmptyExpr(..),
+            ..
+        }) => true,
+        _ => false,
     }
 }
 
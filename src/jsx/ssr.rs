@@ -0,0 +1,645 @@
+use swc_core::common::comments::Comments;
+use swc_core::common::{DUMMY_SP, Span, Spanned};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::{ExprFactory, private_ident};
+use swc_core::plugin::errors::HANDLER;
+
+use super::attr::{jsx_attr_value_to_expr, jsx_attr_value_to_expr_or_invalid, take_marker_attr};
+use super::inline_helpers::parse_inline_helper;
+use super::text::{jsx_text_to_str, needs_leading_newline, preserve_element_whitespace};
+use super::{Jsx, is_component_vnode, is_empty_child};
+use crate::transformations::lowercase_attrs::requires_lowercasing;
+use crate::transformations::transform_attribute::transform_attribute;
+
+/// Functions injected into modules compiled with `ssr`, only the ones which
+/// are used end up in the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SsrHelper {
+    /// Wraps rendered HTML, so it is not escaped again when used as a child
+    Markup,
+    Child,
+    Escape,
+    Attr,
+    Style,
+    Spread,
+    Component,
+}
+
+impl SsrHelper {
+    pub(super) const ALL: [SsrHelper; 7] = [
+        SsrHelper::Markup,
+        SsrHelper::Child,
+        SsrHelper::Escape,
+        SsrHelper::Attr,
+        SsrHelper::Style,
+        SsrHelper::Spread,
+        SsrHelper::Component,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            SsrHelper::Markup => "ssrMarkup",
+            SsrHelper::Child => "ssrChild",
+            SsrHelper::Escape => "ssrEscape",
+            SsrHelper::Attr => "ssrAttr",
+            SsrHelper::Style => "ssrStyle",
+            SsrHelper::Spread => "ssrSpread",
+            SsrHelper::Component => "ssrComponent",
+        }
+    }
+
+    fn dependencies(self) -> &'static [SsrHelper] {
+        match self {
+            SsrHelper::Markup | SsrHelper::Escape | SsrHelper::Style => &[],
+            SsrHelper::Child | SsrHelper::Attr => &[SsrHelper::Escape],
+            SsrHelper::Spread => &[SsrHelper::Attr, SsrHelper::Style],
+            SsrHelper::Component => &[SsrHelper::Child],
+        }
+    }
+
+    /// Written as ES5, the output of the pass may not be transpiled further
+    fn source(self) -> &'static str {
+        match self {
+            SsrHelper::Markup => {
+                r#"function ssrMarkup(html) {
+                    return { __ssr: html, toString: function () { return html; } };
+                }"#
+            }
+            // Adjacent text nodes are separated like inferno-server does, so
+            // hydration can tell them apart. Only markup of this pass is
+            // known, vNodes created at runtime are escaped like any object.
+            SsrHelper::Child => {
+                r#"function ssrChild(value) {
+                    if (value == null || typeof value === "boolean") return "";
+                    if (Array.isArray(value)) {
+                        var html = "";
+                        var wasText = false;
+                        for (var i = 0; i < value.length; i++) {
+                            var isText = typeof value[i] === "string" || typeof value[i] === "number";
+                            if (isText && wasText) html += "<!---->";
+                            html += ssrChild(value[i]);
+                            wasText = isText;
+                        }
+                        return html;
+                    }
+                    if (typeof value === "object" && "__ssr" in value) return value.__ssr;
+                    return ssrEscape(value);
+                }"#
+            }
+            SsrHelper::Escape => {
+                r#"function ssrEscape(value) {
+                    return String(value).replace(/[&<>"']/g, function (c) {
+                        return c === "&" ? "&amp;" : c === "<" ? "&lt;" : c === ">" ? "&gt;" : c === '"' ? "&quot;" : "&#039;";
+                    });
+                }"#
+            }
+            SsrHelper::Attr => {
+                r#"function ssrAttr(name, value) {
+                    if (value == null || value === false || typeof value === "function") return "";
+                    return value === true ? " " + name : " " + name + '="' + ssrEscape(value) + '"';
+                }"#
+            }
+            SsrHelper::Style => {
+                r#"function ssrStyle(value) {
+                    if (value == null || typeof value !== "object") return value;
+                    var css = "";
+                    for (var name in value) {
+                        if (value[name] != null && value[name] !== "") css += name + ":" + value[name] + ";";
+                    }
+                    return css;
+                }"#
+            }
+            SsrHelper::Spread => {
+                r#"function ssrSpread(props) {
+                    var html = "";
+                    for (var name in props) {
+                        var value = props[name];
+                        if (name === "children" || name === "key" || name === "ref" || name === "dangerouslySetInnerHTML" || /^on[A-Z]/.test(name)) continue;
+                        if (name === "className") name = "class";
+                        else if (name === "htmlFor") name = "for";
+                        else if (name === "defaultValue") name = "value";
+                        else if (name === "defaultChecked") name = "checked";
+                        else if (name === "style") value = ssrStyle(value);
+                        html += ssrAttr(name, value);
+                    }
+                    return html;
+                }"#
+            }
+            // Context is not supported, the element children of a component
+            // are rendered before the component itself could provide it
+            SsrHelper::Component => {
+                r#"function ssrComponent(Component, props) {
+                    var defaults = Component.defaultProps;
+                    if (defaults) {
+                        props = Object.assign({}, props);
+                        for (var name in defaults) {
+                            if (props[name] === undefined) props[name] = defaults[name];
+                        }
+                    }
+                    var rendered;
+                    if (Component.prototype && Component.prototype.render) {
+                        var instance = new Component(props, {});
+                        instance.props = props;
+                        if (Component.getDerivedStateFromProps) {
+                            instance.state = Object.assign({}, instance.state, Component.getDerivedStateFromProps(props, instance.state));
+                        } else if (instance.componentWillMount) {
+                            instance.componentWillMount();
+                        }
+                        rendered = instance.render(props, instance.state, {});
+                    } else {
+                        rendered = Component(props, {});
+                    }
+                    return rendered == null || typeof rendered === "boolean" ? "<!--!-->" : ssrChild(rendered);
+                }"#
+            }
+        }
+    }
+}
+
+/// Elements which never have children or a closing tag
+pub(super) const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose content is parsed as text, a `<!---->` separator would show
+/// up as part of it
+const TEXT_ONLY_ELEMENTS: [&str; 4] = ["script", "style", "textarea", "title"];
+
+/// Rendered HTML as string literals and expressions, adjacent literals are
+/// merged
+#[derive(Default)]
+struct Html {
+    parts: Vec<Expr>,
+    text: String,
+}
+
+impl Html {
+    fn push_str(&mut self, s: &str) {
+        self.text.push_str(s);
+    }
+
+    fn push_expr(&mut self, expr: Expr) {
+        self.flush();
+        self.parts.push(expr);
+    }
+
+    fn flush(&mut self) {
+        if !self.text.is_empty() {
+            let text = std::mem::take(&mut self.text);
+            self.parts.push(text.into());
+        }
+    }
+
+    /// `"<div>" + ssrChild(x) + "</div>"`, every part is a string
+    fn into_expr(mut self) -> Expr {
+        self.flush();
+
+        let mut parts = self.parts.into_iter();
+        let first = parts.next().unwrap_or_else(|| "".into());
+
+        parts.fold(first, |left, right| {
+            Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                op: BinaryOp::Add,
+                left: Box::new(left),
+                right: Box::new(right),
+            })
+        })
+    }
+}
+
+impl<C> Jsx<C>
+where
+    C: Comments,
+{
+    /// `<div class="a">{x}</div>` => `ssrMarkup("<div class=\"a\">" + ssrChild(x) + "</div>")`
+    pub(super) fn ssr_elem_to_expr(&mut self, el: JSXElement) -> Expr {
+        let span = el.span;
+        let mut html = Html::default();
+        self.ssr_write_element(el, &mut html);
+
+        self.ssr_markup(span, html)
+    }
+
+    pub(super) fn ssr_frag_to_expr(&mut self, frag: JSXFragment) -> Expr {
+        let span = frag.span;
+        let mut html = Html::default();
+        self.ssr_write_children(frag.children, true, &mut html);
+
+        self.ssr_markup(span, html)
+    }
+
+    fn ssr_markup(&mut self, span: Span, html: Html) -> Expr {
+        if let Some(comments) = &self.comments {
+            comments.add_pure_comment(span.lo);
+        }
+
+        Expr::Call(CallExpr {
+            span,
+            callee: self.ssr_helper(SsrHelper::Markup).as_callee(),
+            args: vec![html.into_expr().as_arg()],
+            ..Default::default()
+        })
+    }
+
+    fn ssr_call(&mut self, helper: SsrHelper, args: Vec<ExprOrSpread>) -> Expr {
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: self.ssr_helper(helper).as_callee(),
+            args,
+            ..Default::default()
+        })
+    }
+
+    fn ssr_helper(&mut self, helper: SsrHelper) -> Ident {
+        self.ssr_helpers[helper as usize]
+            .get_or_insert_with(|| private_ident!(helper.name()))
+            .clone()
+    }
+
+    fn ssr_write_element(&mut self, mut el: JSXElement, html: &mut Html) {
+        if self.is_fragment_name(&el.opening.name) {
+            self.ssr_write_children(el.children, true, html);
+            return;
+        }
+
+        // Portals are rendered on the client only
+        if self.is_portal_name(&el.opening.name) {
+            return;
+        }
+
+        if let Some(control) = self.control_statement(&el.opening.name) {
+            let expr = self.jsx_control_to_expr(el, control);
+            html.push_expr(self.ssr_call(SsrHelper::Child, vec![expr.as_arg()]));
+            return;
+        }
+
+        preserve_element_whitespace(&mut el);
+        if take_marker_attr(&mut el.opening.attrs, "$Tag").is_some() {
+            ssr_error(el.opening.span, "$Tag is not supported when ssr is enabled");
+        }
+
+        let tag = match &el.opening.name {
+            JSXElementName::Ident(ident) if ident.sym != "this" && !is_component_vnode(ident) => {
+                ident.sym.clone()
+            }
+            JSXElementName::JSXNamespacedName(..) => {
+                ssr_error(el.opening.name.span(), "JSX Namespace is disabled");
+                return;
+            }
+            _ => {
+                self.ssr_write_component(el, html);
+                return;
+            }
+        };
+
+        html.push_str("<");
+        html.push_str(&tag);
+
+        let mut content = None;
+        let mut prop_children = None;
+
+        for attr in el.opening.attrs {
+            let attr = match attr {
+                JSXAttrOrSpread::JSXAttr(attr) => attr,
+                JSXAttrOrSpread::SpreadElement(spread) => {
+                    html.push_expr(self.ssr_call(SsrHelper::Spread, vec![spread.expr.as_arg()]));
+                    continue;
+                }
+            };
+
+            let name = match attr.name {
+                JSXAttrName::Ident(name) => name.sym,
+                JSXAttrName::JSXNamespacedName(JSXNamespacedName { ns, name, .. }) => {
+                    format!("{}:{}", ns.sym, name.sym).into()
+                }
+            };
+
+            let name = match &*name {
+                "key" | "ref" => continue,
+                name if name.starts_with('$') || is_event(name) => continue,
+                "children" => {
+                    prop_children = attr.value.and_then(jsx_attr_value_to_expr);
+                    continue;
+                }
+                "dangerouslySetInnerHTML" => {
+                    if let Some(value) = attr.value.and_then(jsx_attr_value_to_expr) {
+                        // `{ __html }` is inserted as is, `[html].join("")` is
+                        // an ES5 `html ?? ""` which evaluates `value` once
+                        let html = value.make_member(IdentName::new("__html".into(), DUMMY_SP));
+                        content = Some(Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: ArrayLit {
+                                span: DUMMY_SP,
+                                elems: vec![Some(html.as_arg())],
+                            }
+                            .make_member(IdentName::new("join".into(), DUMMY_SP))
+                            .as_callee(),
+                            args: vec!["".as_arg()],
+                            ..Default::default()
+                        }));
+                    }
+                    continue;
+                }
+                "value" | "defaultValue" if tag == "textarea" => {
+                    if let Some(value) = attr.value.and_then(jsx_attr_value_to_expr) {
+                        content = Some(self.ssr_call(SsrHelper::Child, vec![value.as_arg()]));
+                    }
+                    continue;
+                }
+                "className" => "class".into(),
+                "defaultValue" => "value".into(),
+                "defaultChecked" => "checked".into(),
+                name if requires_lowercasing(name) => name.to_lowercase(),
+                name => transform_attribute(name).to_string(),
+            };
+
+            let Some(value) = attr.value else {
+                html.push_str(&format!(" {name}"));
+                continue;
+            };
+
+            let value = jsx_attr_value_to_expr_or_invalid(value, attr.span);
+            self.ssr_write_attr(&name, *value, html);
+        }
+
+        html.push_str(">");
+
+        if VOID_ELEMENTS.contains(&&*tag) {
+            return;
+        }
+
+        if let Some(content) = content {
+            html.push_expr(content);
+        } else if el.children.iter().all(is_empty_child) {
+            if let Some(children) = prop_children {
+                html.push_expr(self.ssr_call(SsrHelper::Child, vec![children.as_arg()]));
+            }
+        } else {
+            if needs_leading_newline(&tag, &el.children) {
+                html.push_str("\n");
+            }
+            let separate_text = !TEXT_ONLY_ELEMENTS.contains(&&*tag);
+            self.ssr_write_children(el.children, separate_text, html);
+        }
+
+        html.push_str("</");
+        html.push_str(&tag);
+        html.push_str(">");
+    }
+
+    fn ssr_write_attr(&mut self, name: &str, value: Expr, html: &mut Html) {
+        match value {
+            Expr::Lit(Lit::Str(s)) => {
+                let value = s.value.to_string_lossy();
+                html.push_str(&format!(" {name}=\"{}\"", escape_html(&value)));
+            }
+            Expr::Lit(Lit::Num(n)) => html.push_str(&format!(" {name}=\"{}\"", n.value)),
+            Expr::Lit(Lit::Bool(Bool { value: true, .. })) => html.push_str(&format!(" {name}")),
+            Expr::Lit(Lit::Bool(..) | Lit::Null(..)) => {}
+            value => {
+                let value = if name == "style" {
+                    self.ssr_call(SsrHelper::Style, vec![value.as_arg()])
+                } else {
+                    value
+                };
+
+                let attr = self.ssr_call(SsrHelper::Attr, vec![name.as_arg(), value.as_arg()]);
+                html.push_expr(attr);
+            }
+        }
+    }
+
+    /// Adjacent text is separated by `<!---->` when `separate_text` is set
+    fn ssr_write_children(
+        &mut self,
+        children: Vec<JSXElementChild>,
+        separate_text: bool,
+        html: &mut Html,
+    ) {
+        let mut prev_is_text = false;
+
+        for child in self.flatten_fragments(children) {
+            let is_text = match child {
+                JSXElementChild::JSXText(text) => {
+                    let value = jsx_text_to_str(&*text.value);
+                    if value.is_empty() {
+                        continue;
+                    }
+
+                    if prev_is_text && separate_text {
+                        html.push_str("<!---->");
+                    }
+                    html.push_str(&escape_html(&value.to_string_lossy()));
+                    true
+                }
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) if matches!(*expr, Expr::JSXElement(..) | Expr::JSXFragment(..)) => {
+                    match *expr {
+                        Expr::JSXElement(el) => self.ssr_write_element(*el, html),
+                        Expr::JSXFragment(frag) => {
+                            self.ssr_write_children(frag.children, separate_text, html)
+                        }
+                        _ => {}
+                    }
+                    false
+                }
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) => {
+                    if prev_is_text && separate_text {
+                        html.push_str("<!---->");
+                    }
+
+                    match *expr {
+                        Expr::Lit(Lit::Str(s)) => {
+                            html.push_str(&escape_html(&s.value.to_string_lossy()))
+                        }
+                        expr => {
+                            html.push_expr(self.ssr_call(SsrHelper::Child, vec![expr.as_arg()]))
+                        }
+                    }
+                    true
+                }
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::JSXEmptyExpr(..),
+                    ..
+                }) => continue,
+                JSXElementChild::JSXElement(el) => {
+                    self.ssr_write_element(*el, html);
+                    false
+                }
+                JSXElementChild::JSXFragment(frag) => {
+                    self.ssr_write_children(frag.children, separate_text, html);
+                    false
+                }
+                JSXElementChild::JSXSpreadChild(JSXSpreadChild { expr, .. }) => {
+                    html.push_expr(self.ssr_call(SsrHelper::Child, vec![expr.as_arg()]));
+                    false
+                }
+            };
+
+            prev_is_text = is_text;
+        }
+    }
+
+    /// `<Foo a={1}>text</Foo>` => `ssrComponent(Foo, { a: 1, children: "text" })`
+    fn ssr_write_component(&mut self, el: JSXElement, html: &mut Html) {
+        let name = jsx_name_to_expr(el.opening.name);
+        let has_children = !el.children.iter().all(is_empty_child);
+        let mut props = vec![];
+
+        for attr in el.opening.attrs {
+            let attr = match attr {
+                JSXAttrOrSpread::JSXAttr(attr) => attr,
+                JSXAttrOrSpread::SpreadElement(spread) => {
+                    props.push(PropOrSpread::Spread(spread));
+                    continue;
+                }
+            };
+
+            let key = match attr.name {
+                JSXAttrName::Ident(name) => {
+                    if matches!(&*name.sym, "key" | "ref")
+                        || name.sym.starts_with('$')
+                        || name.sym.starts_with("onComponent")
+                        || (has_children && name.sym == "children")
+                    {
+                        continue;
+                    }
+
+                    PropName::Ident(name)
+                }
+                JSXAttrName::JSXNamespacedName(JSXNamespacedName { ns, name, .. }) => {
+                    PropName::Str(format!("{}:{}", ns.sym, name.sym).into())
+                }
+            };
+
+            let value = match attr.value {
+                Some(value) => jsx_attr_value_to_expr_or_invalid(value, attr.span),
+                None => true.into(),
+            };
+
+            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key,
+                value,
+            }))));
+        }
+
+        if has_children {
+            let children = self.ssr_component_children(el.span, el.children);
+
+            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(IdentName::new("children".into(), DUMMY_SP)),
+                value: Box::new(children),
+            }))));
+        }
+
+        let props = ObjectLit {
+            span: DUMMY_SP,
+            props,
+        };
+        html.push_expr(self.ssr_call(SsrHelper::Component, vec![name.as_arg(), props.as_arg()]));
+    }
+
+    /// A single text or expression child is passed as is, like a render
+    /// callback, anything else as rendered markup
+    fn ssr_component_children(&mut self, span: Span, children: Vec<JSXElementChild>) -> Expr {
+        let mut significant = children.iter().filter(|child| !is_empty_child(child));
+
+        match (significant.next(), significant.next()) {
+            (Some(JSXElementChild::JSXText(text)), None) => {
+                Expr::Lit(Lit::Str(jsx_text_to_str(&*text.value).into()))
+            }
+            (
+                Some(JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                })),
+                None,
+            ) => *expr.clone(),
+            _ => {
+                let mut html = Html::default();
+                self.ssr_write_children(children, true, &mut html);
+                self.ssr_markup(span, html)
+            }
+        }
+    }
+
+    /// Declarations of the helpers used by the module, including the ones
+    /// they depend on
+    pub(super) fn take_ssr_helpers(&mut self) -> Vec<Stmt> {
+        for helper in SsrHelper::ALL {
+            if self.ssr_helpers[helper as usize].is_some() {
+                for dependency in helper.dependencies() {
+                    self.ssr_helper(*dependency);
+                }
+            }
+        }
+
+        let mut locals = vec![];
+        for helper in SsrHelper::ALL {
+            if let Some(local) = &self.ssr_helpers[helper as usize] {
+                locals.push((helper.name(), local.clone()));
+            }
+        }
+
+        let mut stmts = vec![];
+        for helper in SsrHelper::ALL {
+            if self.ssr_helpers[helper as usize].take().is_some() {
+                stmts.extend(parse_inline_helper(helper.source(), &locals));
+            }
+        }
+
+        stmts
+    }
+}
+
+fn ssr_error(span: Span, msg: &str) {
+    HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+}
+
+/// `onClick`, handlers are attached when the client hydrates
+pub(super) fn is_event(name: &str) -> bool {
+    name.strip_prefix("on")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+pub(super) fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#039;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn jsx_name_to_expr(name: JSXElementName) -> Expr {
+    fn convert_obj(obj: JSXObject) -> Expr {
+        match obj {
+            JSXObject::Ident(i) if i.sym == "this" => Expr::This(ThisExpr { span: i.span }),
+            JSXObject::Ident(i) => Expr::Ident(i),
+            JSXObject::JSXMemberExpr(e) => convert_obj(e.obj).make_member(e.prop).into(),
+        }
+    }
+
+    match name {
+        JSXElementName::Ident(i) if i.sym == "this" => Expr::This(ThisExpr { span: i.span }),
+        JSXElementName::Ident(i) => Expr::Ident(i),
+        JSXElementName::JSXMemberExpr(JSXMemberExpr { obj, prop, .. }) => {
+            convert_obj(obj).make_member(prop).into()
+        }
+        JSXElementName::JSXNamespacedName(..) => Expr::Invalid(Invalid { span: DUMMY_SP }),
+    }
+}
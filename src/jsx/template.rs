@@ -0,0 +1,474 @@
+use swc_core::atoms::Atom;
+use swc_core::common::{BytePos, DUMMY_SP, Span};
+use swc_core::ecma::ast::*;
+
+/// A character of the template or one of its `${}` expressions
+enum Token<'a> {
+    Char(char, BytePos),
+    Expr(&'a Expr),
+}
+
+/// Parses an htm style tagged template, `` html`<div class=${c}>${x}</div>` ``,
+/// into the JSX it is equivalent to. More than one root node produces a
+/// fragment.
+///
+/// Static tag names are elements, components are referenced as `<${Component}>`
+/// and closed with `</${Component}>` or `<//>`.
+pub(super) fn parse_html_template(tpl: &Tpl) -> Result<Expr, (Span, String)> {
+    let mut tokens = vec![];
+    for (index, quasi) in tpl.quasis.iter().enumerate() {
+        let start = quasi.span.lo;
+        // Escapes are evaluated like in any other template literal
+        let text = quasi
+            .cooked
+            .as_ref()
+            .map(|cooked| cooked.to_string_lossy().into_owned())
+            .unwrap_or_else(|| quasi.raw.to_string());
+        for (offset, c) in text.char_indices() {
+            tokens.push(Token::Char(c, start + BytePos(offset as u32)));
+        }
+
+        if let Some(expr) = tpl.exprs.get(index) {
+            tokens.push(Token::Expr(expr));
+        }
+    }
+
+    let mut parser = TemplateParser {
+        tokens,
+        pos: 0,
+        span: tpl.span,
+    };
+
+    let children = parser.parse_children()?;
+    if parser.pos < parser.tokens.len() {
+        return Err(parser.error("Unexpected closing tag"));
+    }
+
+    let mut roots = children.iter().filter(|child| !is_whitespace_text(child));
+    if let (Some(JSXElementChild::JSXElement(root)), None) = (roots.next(), roots.next()) {
+        return Ok(Expr::JSXElement(root.clone()));
+    }
+
+    Ok(Expr::JSXFragment(JSXFragment {
+        span: tpl.span,
+        opening: JSXOpeningFragment { span: tpl.span },
+        children,
+        closing: JSXClosingFragment { span: DUMMY_SP },
+    }))
+}
+
+fn is_whitespace_text(child: &JSXElementChild) -> bool {
+    matches!(child, JSXElementChild::JSXText(text) if text.value.trim().is_empty())
+}
+
+struct TemplateParser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    span: Span,
+}
+
+impl TemplateParser<'_> {
+    fn error(&self, msg: &str) -> (Span, String) {
+        (self.span, format!("Invalid html template: {msg}"))
+    }
+
+    /// Position of the current token, for the spans of the parsed nodes
+    fn lo(&self) -> BytePos {
+        self.tokens
+            .iter()
+            .skip(self.pos)
+            .chain(self.tokens.iter().rev())
+            .find_map(|token| match token {
+                Token::Char(_, pos) => Some(*pos),
+                Token::Expr(..) => None,
+            })
+            .unwrap_or(self.span.lo)
+    }
+
+    fn span_from(&self, lo: BytePos) -> Span {
+        Span::new(lo, self.lo().max(lo))
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.peek_char_at(0)
+    }
+
+    fn peek_char_at(&self, offset: usize) -> Option<char> {
+        match self.tokens.get(self.pos + offset)? {
+            Token::Char(c, _) => Some(*c),
+            Token::Expr(..) => None,
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let matches = s
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| self.peek_char_at(offset) == Some(c));
+        if matches {
+            self.pos += s.chars().count();
+        }
+
+        matches
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), (Span, String)> {
+        if self.eat_str(s) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{s}'")))
+        }
+    }
+
+    fn eat_expr(&mut self) -> Option<&Expr> {
+        match self.tokens.get(self.pos)? {
+            Token::Expr(expr) => {
+                let expr = *expr;
+                self.pos += 1;
+                Some(expr)
+            }
+            Token::Char(..) => None,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek_char().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat_name(&mut self) -> Option<String> {
+        let mut name = String::new();
+        while let Some(c) = self
+            .peek_char()
+            .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '$'))
+        {
+            name.push(c);
+            self.pos += 1;
+        }
+
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// Nodes up to the next closing tag or the end of the template
+    fn parse_children(&mut self) -> Result<Vec<JSXElementChild>, (Span, String)> {
+        let mut children = vec![];
+        let mut text = String::new();
+        let mut text_lo = self.lo();
+
+        loop {
+            if self.pos >= self.tokens.len() || self.eat_str("</") {
+                if self.pos < self.tokens.len() {
+                    // Leave the closing tag to the element
+                    self.pos -= 2;
+                }
+                break;
+            }
+
+            let child = if self.eat_str("<!--") {
+                while !self.eat_str("-->") {
+                    if self.pos >= self.tokens.len() {
+                        return Err(self.error("Unterminated comment"));
+                    }
+                    self.pos += 1;
+                }
+                None
+            } else if self.peek_char() == Some('<') {
+                Some(JSXElementChild::JSXElement(Box::new(self.parse_element()?)))
+            } else if let Some(expr) = self.eat_expr() {
+                Some(JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    span: DUMMY_SP,
+                    expr: JSXExpr::Expr(Box::new(expr.clone())),
+                }))
+            } else {
+                if text.is_empty() {
+                    text_lo = self.lo();
+                }
+                text.extend(self.peek_char());
+                self.pos += 1;
+                continue;
+            };
+
+            if !text.is_empty() {
+                children.push(jsx_text(&text, self.span_from(text_lo)));
+                text.clear();
+            }
+            children.extend(child);
+        }
+
+        if !text.is_empty() {
+            children.push(jsx_text(&text, self.span_from(text_lo)));
+        }
+
+        Ok(children)
+    }
+
+    fn parse_element(&mut self) -> Result<JSXElement, (Span, String)> {
+        let lo = self.lo();
+        self.expect("<")?;
+
+        let (name, dynamic_tag) = self.parse_element_name()?;
+        let mut attrs = vec![];
+        if dynamic_tag {
+            attrs.push(JSXAttrOrSpread::JSXAttr(JSXAttr {
+                span: DUMMY_SP,
+                name: JSXAttrName::Ident(IdentName::new("$Tag".into(), DUMMY_SP)),
+                value: None,
+            }));
+        }
+
+        loop {
+            self.skip_whitespace();
+
+            if self.eat_str("/>") {
+                let span = self.span_from(lo);
+                return Ok(JSXElement {
+                    span,
+                    opening: JSXOpeningElement {
+                        span,
+                        name,
+                        attrs,
+                        self_closing: true,
+                        type_args: None,
+                    },
+                    children: vec![],
+                    closing: None,
+                });
+            }
+
+            if self.eat_str(">") {
+                break;
+            }
+
+            attrs.push(self.parse_attr()?);
+        }
+
+        let opening_span = self.span_from(lo);
+        let children = self.parse_children()?;
+
+        let closing_lo = self.lo();
+        self.expect("</")?;
+        if !self.eat_str("/") {
+            let closing_name = match self.eat_expr() {
+                Some(..) => None,
+                None => self.eat_name(),
+            };
+
+            if let (JSXElementName::Ident(opening), Some(closing)) = (&name, closing_name)
+                && opening.sym != *closing
+            {
+                return Err(self.error(&format!(
+                    "Expected corresponding closing tag for '{}'",
+                    opening.sym
+                )));
+            }
+        }
+        self.skip_whitespace();
+        self.expect(">")?;
+
+        let span = self.span_from(lo);
+        Ok(JSXElement {
+            span,
+            opening: JSXOpeningElement {
+                span: opening_span,
+                name: name.clone(),
+                attrs,
+                self_closing: false,
+                type_args: None,
+            },
+            children,
+            closing: Some(JSXClosingElement {
+                span: self.span_from(closing_lo),
+                name,
+            }),
+        })
+    }
+
+    /// The name of an element and whether it is held by a lowercase binding,
+    /// like `<${tag}>`, which may be a tag name or a component and is
+    /// compiled like `<Tag $Tag />`
+    fn parse_element_name(&mut self) -> Result<(JSXElementName, bool), (Span, String)> {
+        let lo = self.lo();
+
+        if let Some(expr) = self.eat_expr() {
+            let name = expr_to_element_name(expr).ok_or_else(|| {
+                self.error("Components must be referenced by name, like <${Foo}>")
+            })?;
+            let last_name = match &name {
+                JSXElementName::Ident(ident) => &ident.sym,
+                JSXElementName::JSXMemberExpr(member) => &member.prop.sym,
+                _ => return Ok((name, false)),
+            };
+            let dynamic_tag = !last_name.starts_with(|c: char| c.is_ascii_uppercase());
+
+            return Ok((name, dynamic_tag));
+        }
+
+        match self.eat_name() {
+            Some(name) if name.starts_with(|c: char| c.is_ascii_lowercase()) => Ok((
+                JSXElementName::Ident(Ident::new_no_ctxt(name.into(), self.span_from(lo))),
+                false,
+            )),
+            Some(name) => Err(self.error(&format!(
+                "Use <${{{name}}}> to render the component '{name}'"
+            ))),
+            None => Err(self.error("Expected a tag name")),
+        }
+    }
+
+    fn parse_attr(&mut self) -> Result<JSXAttrOrSpread, (Span, String)> {
+        if self.eat_str("...") {
+            let Some(expr) = self.eat_expr() else {
+                return Err(self.error("Expected an expression after '...'"));
+            };
+
+            return Ok(JSXAttrOrSpread::SpreadElement(SpreadElement {
+                dot3_token: DUMMY_SP,
+                expr: Box::new(expr.clone()),
+            }));
+        }
+
+        let lo = self.lo();
+        let Some(name) = self.eat_name() else {
+            return Err(self.error("Expected an attribute name"));
+        };
+        let span = self.span_from(lo);
+
+        let name = match name.split_once(':') {
+            Some((ns, name)) => JSXAttrName::JSXNamespacedName(JSXNamespacedName {
+                span,
+                ns: IdentName::new(ns.into(), span),
+                name: IdentName::new(name.into(), span),
+            }),
+            None => JSXAttrName::Ident(IdentName::new(name.into(), span)),
+        };
+
+        let value = if self.eat_str("=") {
+            Some(self.parse_attr_value()?)
+        } else {
+            None
+        };
+
+        Ok(JSXAttrOrSpread::JSXAttr(JSXAttr {
+            span: self.span_from(lo),
+            name,
+            value,
+        }))
+    }
+
+    /// `${expr}`, `"static"`, `"mixed ${expr}"` or an unquoted value
+    fn parse_attr_value(&mut self) -> Result<JSXAttrValue, (Span, String)> {
+        if let Some(expr) = self.eat_expr() {
+            return Ok(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                span: DUMMY_SP,
+                expr: JSXExpr::Expr(Box::new(expr.clone())),
+            }));
+        }
+
+        let lo = self.lo();
+        let quote = self.peek_char().filter(|c| matches!(c, '"' | '\''));
+        if quote.is_some() {
+            self.pos += 1;
+        }
+
+        let mut quasis = vec![String::new()];
+        let mut exprs = vec![];
+        loop {
+            match self.tokens.get(self.pos) {
+                None => return Err(self.error("Unterminated attribute value")),
+                Some(Token::Expr(expr)) if quote.is_some() => {
+                    exprs.push(Box::new((*expr).clone()));
+                    quasis.push(String::new());
+                }
+                Some(Token::Char(c, _)) if Some(*c) == quote => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(Token::Char(c, _))
+                    if quote.is_none() && (c.is_whitespace() || *c == '>' || *c == '/') =>
+                {
+                    break;
+                }
+                Some(Token::Char(c, _)) => quasis.last_mut().unwrap().push(*c),
+                Some(Token::Expr(..)) => break,
+            }
+            self.pos += 1;
+        }
+
+        let span = self.span_from(lo);
+        if exprs.is_empty() {
+            let value = quasis.pop().unwrap_or_default();
+
+            return Ok(JSXAttrValue::Str(Str {
+                span,
+                raw: None,
+                value: value.into(),
+            }));
+        }
+
+        // Mixed values are concatenated like htm does
+        let last = quasis.len() - 1;
+        Ok(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(Box::new(Expr::Tpl(Tpl {
+                span,
+                exprs,
+                quasis: quasis
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, quasi)| TplElement {
+                        span,
+                        tail: index == last,
+                        cooked: Some(quasi.as_str().into()),
+                        raw: tpl_raw(&quasi).into(),
+                    })
+                    .collect(),
+            }))),
+        }))
+    }
+}
+
+/// The source of a template literal part whose value is `cooked`
+fn tpl_raw(cooked: &str) -> String {
+    cooked
+        .replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+        .replace('\r', "\\r")
+}
+
+fn jsx_text(text: &str, span: Span) -> JSXElementChild {
+    let value: Atom = text.into();
+
+    JSXElementChild::JSXText(JSXText {
+        span,
+        value: value.clone(),
+        raw: value,
+    })
+}
+
+fn expr_to_element_name(expr: &Expr) -> Option<JSXElementName> {
+    match expr {
+        Expr::Ident(ident) => Some(JSXElementName::Ident(ident.clone())),
+        Expr::Member(member) => Some(JSXElementName::JSXMemberExpr(expr_to_member(member)?)),
+        _ => None,
+    }
+}
+
+fn expr_to_member(member: &MemberExpr) -> Option<JSXMemberExpr> {
+    let MemberProp::Ident(prop) = &member.prop else {
+        return None;
+    };
+
+    let obj = match &*member.obj {
+        Expr::Ident(obj) => JSXObject::Ident(obj.clone()),
+        Expr::Member(obj) => JSXObject::JSXMemberExpr(Box::new(expr_to_member(obj)?)),
+        _ => return None,
+    };
+
+    Some(JSXMemberExpr {
+        span: member.span,
+        obj,
+        prop: prop.clone(),
+    })
+}
@@ -1399,6 +1399,1847 @@ test!(
     "#
 );
 
+/*
+ * Type aware child flags
+ */
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            type_aware_child_flags: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    type_aware_child_flags_should_use_function_component_props,
+    r#"
+import { VNode } from 'inferno';
+
+interface Props {
+    title: string;
+    count: number;
+    icon: VNode;
+    subtitle?: string;
+}
+
+function Title(props: Props) {
+    return <h1>{props.title}</h1>;
+}
+
+const Count = ({ count, icon, subtitle }: Props) => (
+    <div>
+        <span>{count}{' items'}</span>
+        <i>{icon}</i>
+        <small>{subtitle}</small>
+    </div>
+);
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            type_aware_child_flags: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    type_aware_child_flags_should_use_class_component_props,
+    r#"
+import { Component } from 'inferno';
+
+type Props = { title: string; label: 'a' | 'b' };
+
+class Header extends Component<Props> {
+    render() {
+        const { label } = this.props;
+        const heading: JSX.Element = <h2>{label}</h2>;
+
+        return (
+            <header>
+                <h1>{this.props.title}</h1>
+                {heading}
+            </header>
+        );
+    }
+}
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            type_aware_child_flags: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    type_aware_child_flags_should_ignore_other_base_classes,
+    r#"
+import * as Inferno from 'inferno';
+import { Store } from './store';
+
+type Props = { title: string };
+
+class Header extends Inferno.Component<Props> {
+    render() {
+        return <h1>{this.props.title}</h1>;
+    }
+}
+
+class Cached extends Store<Props> {
+    render() {
+        return <h1>{this.props.title}</h1>;
+    }
+}
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            type_aware_child_flags: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    type_aware_child_flags_should_wrap_typed_text_in_fragments,
+    r#"
+const name: string = getName();
+const a = <>{name}<b /></>;
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    type_aware_child_flags_should_be_disabled_by_default,
+    r#"
+function Title(props: { title: string }) {
+    return <h1>{props.title}</h1>;
+}
+"#
+);
+
+/*
+ * Object literal spreads
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_not_normalize_object_spread_with_static_keys,
+    r#"
+<div {...{ id: 'x', title, 'data-foo': 1, onClick() {} }}>1</div>;
+<Com {...{ id: 'x', title }} />;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_normalize_object_spread_with_unknown_or_special_keys,
+    r#"
+<div {...{ [name]: 'x' }} />;
+<div {...{ id: 'x', ...rest }} />;
+<div {...{ id: 'x', children: c }} />;
+<Com {...{ get title() { return t; } }} />;
+<div {...{ __proto__: p, id: 1 }} />;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_hoist_key_ref_and_class_name_from_object_spread,
+    r#"
+<div {...{ key: id, className: 'row', ref: rowRef, title }} />;
+<div className="a" {...{ className: 'b' }} />;
+<div {...{ className: 'b', key: 1 }} className="a" key={2} />;
+<Com {...{ key: id, ref, className: 'row' }} />;
+<div {...{ className: 'a' }} {...rest} />;
+<div {...{ className: 'a' }} {...rest} {...{ className: 'b' }} />;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_not_hoist_from_object_spread_with_computed_keys,
+    r#"
+<div {...{ key: id, [name]: 'x' }} />;
+"#
+);
+
+/*
+ * Fragment flattening
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_flatten_nested_keyless_fragments,
+    r#"
+<>
+    <>
+        <a />
+        <b />
+    </>
+    <Fragment><i />{x}</Fragment>
+    <Fragment key="k"><u /></Fragment>
+</>;
+<div><><a /><b /></></div>;
+<Com><><a /><b /></></Com>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_replace_single_element_fragment_with_element,
+    r#"
+const a = <><div /></>;
+const b = (
+    <Fragment>
+        {/* comment */}
+        <Com />
+    </Fragment>
+);
+const c = <><>text</></>;
+const d = <>{x}</>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_replace_empty_fragment_with_null,
+    r#"
+const a = cond ? <></> : <div />;
+const b = <div><></>text</div>;
+"#
+);
+
+/*
+ * Fragment resolution
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_resolve_aliased_and_namespaced_fragment_imports,
+    r#"
+import { Fragment as F } from 'inferno';
+import * as Inferno from 'inferno';
+
+<F key="a"><a /><b /></F>;
+<Inferno.Fragment key="b"><a /><b /></Inferno.Fragment>;
+<React.Fragment key="c"><a /><b /></React.Fragment>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_resolve_fragment_imports_of_inferno_compat,
+    r#"
+import React from 'inferno-compat';
+import * as Compat from 'react';
+import { Fragment as CompatFragment } from 'inferno-compat';
+
+<React.Fragment key="a"><a /><b /></React.Fragment>;
+<Compat.Fragment key="b"><a /><b /></Compat.Fragment>;
+<CompatFragment key="c"><a /><b /></CompatFragment>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_not_treat_user_defined_fragment_as_inferno_fragment,
+    r#"
+import { Fragment } from './my-fragment';
+import * as Lib from 'some-lib';
+
+<Fragment><a /><b /></Fragment>;
+<Lib.Fragment><a /><b /></Lib.Fragment>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_not_treat_local_fragment_component_as_inferno_fragment,
+    r#"
+function Fragment(props) {
+    return <section>{props.children}</section>;
+}
+
+<Fragment><a /><b /></Fragment>;
+"#
+);
+
+/*
+ * Runtime helper hygiene
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_not_be_affected_by_shadowed_helper_names,
+    r#"
+function App() {
+    const createVNode = 1;
+    const createTextVNode = 2;
+
+    return <div><b />text</div>;
+}
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_reuse_aliased_helper_imports,
+    r#"
+import { createVNode as cv, render } from 'inferno';
+
+function App(createComponentVNode) {
+    return <div><Child /></div>;
+}
+"#
+);
+
+/*
+ * Namespace imports
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_call_helpers_through_namespace_import,
+    r#"
+import * as Inferno from 'inferno';
+
+Inferno.render(<div>{text}<Child {...props} /></div>, container);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_call_helpers_through_default_import,
+    r#"
+import Inferno from 'inferno';
+
+const el = <><div /><span /></>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_prefer_named_imports_over_namespace_import,
+    r#"
+import * as Inferno from 'inferno';
+import { createVNode } from 'inferno';
+
+const el = <div><Child /></div>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_not_call_helpers_through_type_only_imports,
+    r#"
+import type * as Inferno from 'inferno';
+import { type createComponentVNode, render } from 'inferno';
+
+const el: Inferno.VNode = <div><Child /></div>;
+render(el, root);
+"#
+);
+
+/*
+ * Unused imports
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_remove_fragment_import_used_only_in_jsx,
+    r#"
+import { Fragment, render } from 'inferno';
+import { createElement } from 'inferno';
+
+render(<Fragment key="a"><div /></Fragment>, container);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_keep_fragment_import_referenced_outside_jsx,
+    r#"
+import { Fragment, createElement } from 'inferno';
+
+const el = <Fragment key="a"><div /></Fragment>;
+export { Fragment };
+export const legacy = createElement('div');
+"#
+);
+
+/*
+ * Dynamic tags
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_compile_dynamic_tag_as_element,
+    r#"
+function Box(props) {
+    const Tag = props.as || 'div';
+
+    return <Tag $Tag className="box" onClick={props.onClick}>{props.children}</Tag>;
+}
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_combine_dynamic_tag_flags_with_static_flags,
+    r#"
+const a = <props.tag $Tag contentEditable $ReCreate />;
+const b = <Tag $Tag $Flags={1} />;
+const c = <Tag />;
+"#
+);
+
+/*
+ * Portals
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_compile_portal_to_create_portal,
+    r#"
+import { Portal } from 'inferno';
+
+const single = <div><Portal container={document.body}><Modal /></Portal></div>;
+const many = (
+    <Portal container={el}>
+        <b />
+        text
+    </Portal>
+);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_not_treat_unimported_portal_as_builtin,
+    r#"
+import { Portal } from './portal';
+
+const el = <Portal container={document.body}><Modal /></Portal>;
+"#
+);
+
+/*
+ * Control statements
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            control_statements: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_compile_if_and_choose_to_conditionals,
+    r#"
+const a = (
+    <div>
+        <If condition={loggedIn}>
+            <Avatar />
+        </If>
+        <Choose>
+            <When condition={count === 0}>empty</When>
+            <When condition={count === 1}><b>one</b></When>
+            <Otherwise><i>many</i><i>items</i></Otherwise>
+        </Choose>
+    </div>
+);
+const b = <If condition={visible}><span /></If>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            control_statements: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_compile_for_to_map_with_list_child_flags,
+    r#"
+const keyed = (
+    <ul>
+        <For each="item" index="i" of={items}>
+            <li key={item.id}>{i}</li>
+        </For>
+    </ul>
+);
+const nonKeyed = <ul><For each="item" of={items}><li>{item}</li></For></ul>;
+const nested = <ul><li /><For each="item" of={items}><li key={item} /></For></ul>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            control_statements: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_bind_for_variables_past_shadowing_bindings,
+    r#"
+const shadowed = (
+    <ul>
+        <For each="item" of={items}>
+            <li key={item.id}>{item.tags.map((item) => item.label)}</li>
+        </For>
+    </ul>
+);
+const empty = <ul><For each="item" of={items}>{/* nothing yet */}</For></ul>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            control_statements: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_normalize_for_bodies_which_may_be_null,
+    r#"
+const conditional = (
+    <ul>
+        <For each="x" of={xs}>
+            <If condition={x.ok}><li key={x.id} /></If>
+        </For>
+    </ul>
+);
+const chosen = (
+    <ul>
+        <For each="x" of={xs}>
+            <Choose>
+                <When condition={x.ok}><li key={x.id} /></When>
+            </Choose>
+        </For>
+    </ul>
+);
+const text = <p><For each="x" of={xs}>{x.label}</For></p>;
+const several = <dl><For each="x" of={xs}><dt>{x.term}</dt><dd>{x.text}</dd></For></dl>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            control_statements: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_not_compile_bound_control_statement_names,
+    r#"
+import { If } from './if';
+
+const a = <If condition={x}><b /></If>;
+"#
+);
+
+/*
+ * createElement
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            compile_create_element: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_compile_create_element_calls,
+    r#"
+import { createElement } from 'inferno-create-element';
+
+const list = createElement(
+    'ul',
+    { className: 'list', 'data-id': id },
+    createElement('li', { key: 'a' }, 'first'),
+    createElement('li', { key: 'b' }, text)
+);
+const component = createElement(Button, { onClick, ...rest }, 'click');
+const element = createElement('input', props);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            compile_create_element: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_keep_create_element_calls_which_can_not_be_compiled,
+    r#"
+import * as CE from 'inferno-create-element';
+
+const dynamic = CE.createElement(tag, null);
+const member = CE.createElement(props.tag, null);
+const memberComponent = CE.createElement(UI.Button, null);
+const renamed = CE.createElement('label', { htmlFor: 'name' });
+const computed = CE.createElement('div', { [name]: value });
+const nested = CE.createElement('div', null, CE.createElement(Component, null));
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_not_compile_create_element_by_default,
+    r#"
+import { createElement } from 'inferno-create-element';
+
+const el = createElement('div', null);
+"#
+);
+
+/*
+ * Hyperscript
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            compile_hyperscript: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_compile_hyperscript_selectors,
+    r#"
+import { h } from 'inferno-hyperscript';
+
+const card = h('div.card.is-active#main', { className: 'wide', onClick }, [
+    h('h2.title', 'Title'),
+    h('p', null, text),
+    h(Button, { key: 'ok' }, 'Ok'),
+]);
+const idProp = h('#main', { id: 'other' });
+const input = h('INPUT', { value });
+const zero = h('div', 0);
+const empty = h('p', null, '');
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            compile_hyperscript: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_keep_hyperscript_calls_which_depend_on_runtime_values,
+    r#"
+import { h } from 'inferno-hyperscript';
+
+const dynamicClass = h('div.card', { className: cls });
+const spread = h('div#main', props, 'text');
+const ambiguous = h('div', propsOrChildren);
+const selector = h('div ' + name);
+const hooks = h(Child, { hooks });
+const member = h(props.tag, { id });
+const memberComponent = h(UI.Button, { id });
+"#
+);
+
+/*
+ * Tagged templates
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            html_template_tags: Some(vec!["html".into()]),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_compile_html_tagged_templates,
+    r#"
+import { Button } from './button';
+
+const card = html`
+    <div class="card ${active}" onClick=${onClick}>
+        <!-- header -->
+        <h2 id=main>${title}</h2>
+        <input disabled value=${value} ...${rest} />
+        <${Button} key="ok">Ok<//>
+        <${Ui.Icon} name="star" />
+    </div>
+`;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            html_template_tags: Some(vec!["html".into()]),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_compile_html_templates_with_several_roots_to_fragments,
+    r#"
+const list = html`<li>One</li><li>${two}</li>`;
+const other = tpl`<div />`;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            html_template_tags: Some(vec!["html".into()]),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_compile_lowercase_html_template_tags_to_dynamic_tags,
+    r#"
+const heading = (comp) => html`
+    <section>
+        <${comp} x=${1} />
+        <${props.tag} class="title">${title}<//>
+    </section>
+`;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            html_template_tags: Some(vec!["html".into()]),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_escape_mixed_html_template_attribute_values,
+    r#"
+const a = html`<div title="C:\\new ${x}" data-tpl="$\{y} \` ${z}" />`;
+"#
+);
+
+/*
+ * Server side rendering
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            ssr: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_compile_elements_to_html_strings,
+    r#"
+import { Header } from './header';
+
+export function Page({ title, items, attrs }) {
+    return (
+        <div className="page" id={title} style={{ color: 'red' }} onClick={() => {}} {...attrs}>
+            <Header title={title} key="header">
+                <span>Menu</span>
+            </Header>
+            <input type="text" disabled value={title} />
+            <p>Hello {title}, "welcome" & bye</p>
+            <ul>{items.map((item) => <li>{item}</li>)}</ul>
+            <textarea value={title} />
+            <div dangerouslySetInnerHTML={{ __html: title }} />
+        </div>
+    );
+}
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            ssr: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_compile_fragments_and_components_to_html_strings,
+    r#"
+const list = (
+    <>
+        <Item>{(value) => value}</Item>
+        <Item text="a" />
+        <Fragment key="b">text</Fragment>
+    </>
+);
+"#
+);
+
+test!(
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            ssr: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_inject_ssr_helpers_into_scripts,
+    r#"
+const el = <div>{x}</div>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            ssr: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_not_separate_text_of_text_only_elements_in_ssr,
+    r#"
+const head = <head><title>Hello {name}</title><style>{css} {extra}</style></head>;
+const form = <form><textarea>Dear {name}</textarea><p>Dear {name}</p></form>;
+"#
+);
+
+/*
+ * Template cloning
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            template_cloning: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_clone_templates_of_static_subtrees,
+    r#"
+const card = (
+    <section className="card" onClick={onClick} key="card">
+        <h2 title={title}>Pricing &amp; plans</h2>
+        <p>
+            Starting at <b>{`${price} EUR`}</b> per month
+        </p>
+        <ul class="features">
+            <li>Fast</li>
+            <li data-id={id}>{"Safe"}</li>
+            <li><img src="a.png" /></li>
+        </ul>
+    </section>
+);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            template_cloning: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_not_count_empty_text_in_template_paths,
+    r#"
+const list = <ul><li>a</li>{""}<li title={t}>b</li><li>c</li></ul>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            template_cloning: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_not_clone_templates_of_dynamic_subtrees,
+    r#"
+const small = <div><span>{a}</span></div>;
+const component = <div><span /><span /><Item /></div>;
+const child = <div><span /><span /><span>{item}</span></div>;
+const event = <div><span /><span /><button onClick={onClick} /></div>;
+const table = <table><tr><td /><td /></tr></table>;
+const nested = <main><div><span /><span /><Item /></div><p><b /><i /><u /></p></main>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            template_cloning: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_not_clone_templates_the_html_parser_restructures,
+    r#"
+const list = <div><p><ul><li>a</li></ul></p><i title={q} /></div>;
+const table = <div><p><table /></p><i /><i title={q} /></div>;
+const section = <div><p><section /><pre /><menu /></p><i title={q} /></div>;
+const items = <div><p><dd /><dt /><li /></p><i title={q} /></div>;
+const button = <div><button><b><button /></b></button><i title={q} /></div>;
+const heading = <div><h1><h2>a</h2></h1><dl><dt>b</dt></dl><i title={q} /></div>;
+const terms = <div><dl><dt><dt /></dt><dd><span><dd /></span></dd></dl><i title={q} /></div>;
+const link = <div><a><span><a /></span></a><i /><i title={q} /></div>;
+const form = <div><form><div><form /></div></form><i /><i title={q} /></div>;
+const nestedLists = <div><ul><li><ul><li>a</li></ul></li></ul><i title={q} /></div>;
+"#
+);
+
+/*
+ * Inline components
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            inline_components: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_inline_trivial_components,
+    r#"
+const Label = ({ text, kind: type }) => <span className={type}>{text}</span>;
+
+function Icon(props) {
+    return <i className={"icon-" + props.name} />;
+}
+
+const Button = ({ label, onClick }) => (
+    <button onClick={onClick}>
+        <Icon name="ok" />
+        <Label text={label} />
+    </button>
+);
+
+const app = (
+    <div>
+        <Label text="Hello" kind="title" key="a" />
+        <Label text={user.name} />
+        <Button label={t("ok")} onClick={save} />
+    </div>
+);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            inline_components: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_not_inline_components_with_runtime_behaviour,
+    r#"
+import { Imported } from './imported';
+
+export const Exported = ({ text }) => <span>{text}</span>;
+
+/* @inline */
+export const Annotated = ({ text }) => <b>{text}</b>;
+
+const WithDefaults = ({ text }) => <span>{text}</span>;
+WithDefaults.defaultProps = { text: 'a' };
+
+const Twice = ({ text }) => <span title={text}>{text}</span>;
+
+const Captured = ({ text }) => <span onClick={() => alert(text)} />;
+
+const Wrapper = ({ children }) => <div>{children}</div>;
+
+const Tree = ({ item }) => <li>{item.children.map((child) => <Tree item={child} />)}</li>;
+
+const Assigned = ({ t }) => <span title={t} />;
+Object.assign(Assigned, { defaultProps: { t: "d" } });
+
+const app = (
+    <div>
+        <Exported text="a" />
+        <Annotated text="b" />
+        <WithDefaults />
+        <Twice text={getText()} />
+        <Twice text={name} />
+        <Captured text={name} />
+        <Captured text="c" />
+        <Wrapper>child</Wrapper>
+        <Tree item={root} />
+        <Imported />
+        <Assigned />
+        <Exported {...props} />
+    </div>
+);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            inline_components: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_not_move_impure_props_past_conditions_or_effects,
+    r#"
+const Label = ({ a, b }) => <span>{a && b}</span>;
+
+const Order = ({ text }) => <span title={log("inner")}>{text}</span>;
+
+const Choice = ({ ok, text }) => <span>{ok ? text : null}</span>;
+
+const Plain = ({ text }) => <span>{text}</span>;
+
+const app = (
+    <div>
+        <Label a={p.ok} b={track()} />
+        <Label a={p.ok} b={p.text} />
+        <Order text={log("outer")} />
+        <Order text={p.text} />
+        <Choice ok={p.ok} text={format()} />
+        <Plain text={format()} />
+    </div>
+);
+"#
+);
+
+/*
+ * Default props
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            merge_default_props: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_merge_default_props_and_remove_redundant_definitions,
+    r#"
+const Button = ({ size, kind, label }) => <button className={size + kind}>{label}</button>;
+Button.defaultProps = { size: 'm', kind: "primary" };
+
+class Card extends Component {
+    static defaultProps = { elevation: 1, title: `Untitled` };
+
+    render() {
+        return <div>{this.props.title}</div>;
+    }
+}
+
+const app = (
+    <div>
+        <Button label="Save" />
+        <Button label="Big" size="l" />
+        <Card />
+        <Card title={<b>Bold</b>} elevation={-1} />
+    </div>
+);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            merge_default_props: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_keep_runtime_default_props_when_needed,
+    r#"
+export function Exported({ size }) {
+    return <span>{size}</span>;
+}
+Exported.defaultProps = { size: 'm' };
+
+const Icon = ({ name, size }) => <i className={name + size} />;
+Icon.defaultProps = { size: 16, style: {} };
+
+const Label = ({ text }) => <span>{text}</span>;
+Label.defaultProps = { text: "label" };
+
+const Spread = ({ text }) => <span>{text}</span>;
+Spread.defaultProps = { text: "spread" };
+
+const Mapped = ({ text }) => <span>{text}</span>;
+Mapped.defaultProps = { text: "mapped" };
+const components = [Mapped];
+
+const app = (
+    <div>
+        <Exported />
+        <Icon name="star" />
+        <Label text={maybeText} />
+        <Spread {...props} />
+        <Mapped />
+    </div>
+);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            merge_default_props: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_not_merge_default_props_which_change,
+    r#"
+const early = <Late />;
+
+const Late = () => <div />;
+Late.defaultProps = { size: 'm' };
+const later = <Late />;
+const render = () => <Late />;
+
+const Read = () => <div />;
+Read.defaultProps = { size: 'm' };
+Read.defaultProps.size = 'l';
+
+const Computed = () => <div />;
+Computed.defaultProps = { [key]: 'm' };
+
+let Reassigned = () => <div />;
+Reassigned.defaultProps = { size: 'm' };
+
+const Replaced = () => <div />;
+Replaced.defaultProps = { size: 'm' };
+Replaced = () => <span />;
+
+const app = (
+    <div>
+        <Read />
+        <Computed />
+        <Reassigned />
+        <Replaced />
+    </div>
+);
+"#
+);
+
+/*
+ * Link events
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            link_events: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_link_inline_event_handlers,
+    r#"
+import { select } from './store';
+
+class List extends Component {
+    render() {
+        return (
+            <ul>
+                {this.props.items.map((item) => (
+                    <li onClick={() => this.select(item.id)} onMouseEnter={(event) => highlight(item, event)}>
+                        {item.name}
+                    </li>
+                ))}
+            </ul>
+        );
+    }
+}
+
+function Toolbar({ store }) {
+    return <button onClick={() => { store.save(); }}>Save</button>;
+}
+
+const Row = ({ row }) => <tr onClick={() => select(row.id)} />;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            link_events: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_not_link_event_handlers_with_several_values,
+    r#"
+const a = (items, index) => items.map((item, i) => (
+    <div>
+        <span onClick={() => select(item, i)} />
+        <span onClick={() => select(items[index])} />
+        <span onClick={() => logout()} />
+        <span onClick={() => item.a && item.b()} />
+        <span onClick={async () => await save(item)} />
+        <span onClick={(a, b) => save(item, a, b)} />
+        <Item onSelect={() => select(item)} />
+        <span onClick={handler} />
+        <span onclick={() => select(item)} />
+    </div>
+));
+
+function f(item) {
+    item = next(item);
+    for (const other of item.others) {
+        use(<span onClick={() => select(other, item)} />);
+    }
+    return <span onClick={() => select(item)} />;
+}
+
+function g() {
+    return [1, 2].map((n) => <span onClick={() => select(n, arguments)} />);
+}
+
+for (const x of xs) {
+    render(list.map((item) => <a onClick={() => f(x, item)} />));
+}
+
+if (ok) {
+    const y = load();
+    render(list.map((item) => <a onClick={() => f(y, item)} />));
+}
+"#
+);
+
+/*
+ * TypeScript wrappers
+ */
+
+test!(
+    module,
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_unwrap_typescript_expressions_around_jsx,
+    r#"
+const a = (<div className="a" />) as unknown as VNode;
+const b = <div className="b" /> satisfies VNode;
+const c = ((<div className="c" />))!;
+const d = (<><span /><span /></>) as const;
+const e = <div>{(<span />) as any}{value as string}</div>;
+render(((<App />)!) as VNode, container);
+"#
+);
+
+/*
+ * Spread children
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_normalize_unknown_spread_children,
+    r#"
+const a = <div>{...items}</div>;
+const b = <div><span />{...items}</div>;
+const c = <>{...items}</>;
+const d = <div>{...items.map((item) => (cond ? <li /> : null))}</div>;
+const e = <div>{...[, <li />]}</div>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_compile_analyzable_spread_children,
+    r#"
+const a = <ul>{...items.map((item) => <li key={item.id}>{item.name}</li>)}</ul>;
+const b = <ul>{...items.map(function (item) { return <li>{item.name}</li>; })}</ul>;
+const c = <ul><li key="first" />{...items.map((item) => <li key={item.id} />)}text</ul>;
+const d = <ul>{...[<li key="a" />, <li key="b" />]}</ul>;
+const e = <>{...items.map((item) => <li key={item.id} />)}</>;
+const f = <><b />{...items.map((item) => <li key={item.id} />)}</>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_pass_spread_children_to_components,
+    r#"
+const a = <List>{...items}</List>;
+const b = <List>{...items.map((item) => <Item key={item.id} />)}</List>;
+const c = <List>{...[<Item />, "text"]}</List>;
+const d = <List><Item />{...rest}</List>;
+"#
+);
+
+/*
+ * Preserved whitespace
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_preserve_whitespace_in_pre_and_textarea,
+    "
+const a = <pre>
+  function add(a, b) {'{'}
+    return a + b;
+  {'}'}
+</pre>;
+const b = <pre><code className=\"js\">
+  const x = 1;\r\n  const y = 2;
+</code>
+</pre>;
+const c = <textarea>
+  Dear sir,
+</textarea>;
+const d = <pre>{items.map((item) => <span>  {item}  </span>)}</pre>;
+const e = <div>
+  collapsed
+</div>;
+"
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_preserve_whitespace_with_marker,
+    r#"
+const a = <div $PreserveWhitespace className="poem">
+    Roses are red,
+        violets are blue
+</div>;
+const b = <Code $PreserveWhitespace>
+    indented
+</Code>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            ssr: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_preserve_whitespace_of_nested_elements_in_ssr,
+    r#"
+const a = <div>
+    <pre>
+  indented
+    code</pre>
+    <p $PreserveWhitespace>  kept  </p>
+</div>;
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            template_cloning: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_preserve_whitespace_of_nested_elements_in_templates,
+    r#"
+const a = <div>
+    <h1>Example</h1>
+    <pre>
+  indented
+    <b>code</b></pre>
+    <p>collapsed
+    text</p>
+</div>;
+"#
+);
+
+/*
+ * Form elements
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_move_textarea_children_to_default_value,
+    r#"
+const a = <textarea className="note">{text}</textarea>;
+const b = <textarea name="count">{count} items</textarea>;
+const c = <textarea>{first}{second}</textarea>;
+const d = <textarea></textarea>;
+const e = <textarea><b>markup</b></textarea>;
+const f = <textarea>{`${count} items`}</textarea>;
+const g = <textarea>Hello {name}</textarea>;
+const h = <textarea>{ok && "x"}</textarea>;
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            type_aware_child_flags: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_move_typed_textarea_children_to_default_value,
+    r#"
+interface Props {
+    name: string;
+    count: number;
+    note?: string;
+}
+
+const Form = ({ name, count, note }: Props) => (
+    <form>
+        <textarea>Hello {name}, {count} items</textarea>
+        <textarea>{note}</textarea>
+    </form>
+);
+"#
+);
+
+// TODO: How to verify warnings
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_keep_textarea_children_next_to_value,
+    r#"
+const a = <textarea value={text}>Ignored</textarea>;
+const b = <textarea defaultValue="draft">{ignored}</textarea>;
+"#
+);
+
+// TODO: How to verify warnings
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_keep_selected_options_of_controlled_select,
+    r#"
+const a = (
+  <select value={size} onChange={onChange}>
+    <option value="s">Small</option>
+    <option value="m" selected>Medium</option>
+    <optgroup label="Large">
+      {sizes.map((size) => <option value={size} selected={size === "l"}>{size}</option>)}
+    </optgroup>
+  </select>
+);
+"#
+);
+
+/*
+ * Define all arguments
+ */
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            define_all_arguments: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_define_all_arguments,
+    r#"
+const a = <div />;
+const b = <div className="box">text</div>;
+const c = <li key={item.id} ref={setRef}>{item.name}</li>;
+const d = <Item />;
+const e = <Item title={title} key="a" />;
+const f = <>
+    <span />
+    <span />
+</>;
+const g = <Fragment key="b">{items}</Fragment>;
+"#
+);
+
 fn tr(t: &mut Tester, options: Options, top_level_mark: Mark) -> Box<dyn Pass> {
     let unresolved_mark = Mark::new();
 
@@ -2378,6 +4219,76 @@ var x = <div>Hello</div>;
 "#
 );
 
+test!(
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_extend_existing_destructuring_require,
+    r#"
+"use strict";
+const { render } = require("inferno");
+render(<div><Child /></div>, container);
+"#
+);
+
+test!(
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_call_helpers_through_namespace_require,
+    r#"
+const Inferno = require('inferno');
+var x = <Inferno.Fragment key="a"><div /></Inferno.Fragment>;
+"#
+);
+
+test!(
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_call_helpers_through_interop_require_wrappers,
+    r#"
+const _inferno = /*#__PURE__*/ _interop_require_default(require("inferno"));
+var x = <div>{text}</div>;
+"#
+);
+
+test!(
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_not_reuse_requires_after_first_jsx,
+    r#"
+"use strict";
+const app = <App />;
+const Inferno = require("inferno");
+const { render } = require("inferno");
+render(<div>{app}</div>, container);
+"#
+);
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(t, Default::default(), Mark::fresh(Mark::root())),
+    should_reuse_interop_require_in_commonjs_module,
+    r#"
+const _inferno = /*#__PURE__*/ _interop_require_wildcard(require("inferno"));
+export const x = <div><_inferno.Fragment><span /><b /></_inferno.Fragment></div>;
+"#
+);
+
 #[testing::fixture("tests/jsx/fixture/**/input.js")]
 fn fixture(input: PathBuf) {
     let mut output = input.with_file_name("output.js");
@@ -2497,3 +4408,24 @@ fn test_script(src: &str, output: &Path, options: Options) {
         Ok(())
     })
 }
+
+test!(
+    module,
+    Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    }),
+    |t| tr(
+        t,
+        Options {
+            template_cloning: Some(true),
+            ..Default::default()
+        },
+        Mark::fresh(Mark::root())
+    ),
+    should_key_template_roots_by_their_template,
+    r#"
+const list = cond ? <ul><li>a</li><li>b</li><li>c</li></ul> : <ul>{items}</ul>;
+const keyed = <ul key={id}><li>a</li><li>b</li><li>c</li></ul>;
+"#
+);
@@ -1,8 +1,11 @@
+use super::attr::take_marker_attr;
 use super::swc_atoms::Wtf8Atom;
 use swc_core::{
     atoms::wtf8::{Wtf8, Wtf8Buf},
+    ecma::ast::*,
     ecma::atoms::Atom,
     ecma::utils::str::is_line_terminator,
+    ecma::visit::{VisitMut, VisitMutWith, noop_visit_mut_type},
 };
 
 /// https://github.com/microsoft/TypeScript/blob/9e20e032effad965567d4a1e1c30d5433b0a3332/src/compiler/transformers/jsx.ts#L572-L608
@@ -167,3 +170,79 @@ fn add_line_of_jsx_text<'a>(
         *only_line = Some(trimmed_line);
     }
 }
+
+/// `<pre>  code</pre>` keeps its text, and that of its descendants, as written,
+/// like elements with the `$PreserveWhitespace` marker. Returns whether the
+/// text of `el` is preserved.
+pub(super) fn preserve_element_whitespace(el: &mut JSXElement) -> bool {
+    let preserves_whitespace = preserves_whitespace(el);
+    take_marker_attr(&mut el.opening.attrs, "$PreserveWhitespace");
+    if preserves_whitespace {
+        preserve_whitespace(&mut el.children);
+    }
+
+    preserves_whitespace
+}
+
+/// `<pre>`, `<textarea>` and elements with the `$PreserveWhitespace` marker
+pub(super) fn preserves_whitespace(el: &JSXElement) -> bool {
+    match &el.opening.name {
+        JSXElementName::Ident(name) if name.sym == "pre" || name.sym == "textarea" => true,
+        _ => el.opening.attrs.iter().any(|attr| {
+            matches!(
+                attr,
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    value: None,
+                    ..
+                }) if name.sym == "$PreserveWhitespace"
+            )
+        }),
+    }
+}
+
+/// Whether the HTML of `children` needs an extra newline after the start tag
+/// of `tag`, as the HTML parser drops the first one of a `<pre>`
+pub(super) fn needs_leading_newline(tag: &str, children: &[JSXElementChild]) -> bool {
+    matches!(tag, "pre" | "textarea" | "listing")
+        && matches!(
+            children.first(),
+            Some(JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            })) if matches!(&**expr, Expr::Lit(Lit::Str(s)) if s.value.starts_with("\n"))
+        )
+}
+
+/// Replaces the text of `children` and of their descendants by string literals
+/// of the text as written, with `\r\n` and `\r` line endings normalized to
+/// `\n`, for elements like `<pre>` where whitespace is significant
+fn preserve_whitespace(children: &mut Vec<JSXElementChild>) {
+    children.visit_mut_with(&mut PreserveWhitespace);
+}
+
+struct PreserveWhitespace;
+
+impl VisitMut for PreserveWhitespace {
+    noop_visit_mut_type!();
+
+    fn visit_mut_jsx_element_child(&mut self, child: &mut JSXElementChild) {
+        let JSXElementChild::JSXText(text) = child else {
+            child.visit_mut_children_with(self);
+            return;
+        };
+
+        let value = text.value.replace("\r\n", "\n").replace('\r', "\n");
+        *child = JSXElementChild::JSXExprContainer(JSXExprContainer {
+            span: text.span,
+            expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Str(Str {
+                span: text.span,
+                raw: None,
+                value: value.into(),
+            })))),
+        });
+    }
+
+    // Attributes are not rendered as text of the element
+    fn visit_mut_jsx_opening_element(&mut self, _: &mut JSXOpeningElement) {}
+}
@@ -0,0 +1,354 @@
+use super::imported_name;
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::{
+    atoms::{Atom, Wtf8Atom},
+    ecma::ast::*,
+    ecma::visit::{Visit, VisitWith},
+};
+
+/// Guards against self referencing type aliases like `type A = B; type B = A;`
+const MAX_RESOLVE_DEPTH: usize = 16;
+
+/// Shape of a child expression as far as local TypeScript annotations can tell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ChildShape {
+    /// `string | number`, rendered as a text child
+    Text,
+    /// A single `VNode` / `JSX.Element`
+    VNode,
+}
+
+enum TypeDecl {
+    Interface(Vec<TsTypeElement>),
+    Alias(Box<TsType>),
+}
+
+/// Local type information used to infer `ChildFlags` for JSX expression
+/// children. Only annotations found in the current module are consulted,
+/// imported types are never resolved.
+#[derive(Default)]
+pub(super) struct TypeScope {
+    decls: FxHashMap<Atom, TypeDecl>,
+    bindings: FxHashMap<Id, Box<TsType>>,
+    /// Props type of the enclosing class component, used for `this.props`.
+    /// `None` entries shadow outer classes inside non-arrow functions.
+    class_props: Vec<Option<Box<TsType>>>,
+    /// Local bindings of `Component` and `PureComponent` imported from the
+    /// import source
+    component_refs: FxHashSet<Id>,
+    /// Namespace and default imports of the import source, for `Inferno.Component`
+    namespace_refs: FxHashSet<Id>,
+}
+
+impl TypeScope {
+    pub(super) fn collect<N>(node: &N, import_source: &Wtf8Atom) -> Self
+    where
+        N: VisitWith<TypeCollector>,
+    {
+        let mut collector = TypeCollector {
+            scope: Default::default(),
+            import_source: import_source.clone(),
+        };
+        node.visit_with(&mut collector);
+
+        let mut scope = collector.scope;
+        scope.class_props.clear();
+        scope
+    }
+
+    /// Only `Component<Props>` and `PureComponent<Props>` of the import source
+    /// are known to take their props type first, other base classes may not.
+    pub(super) fn enter_class(&mut self, class: &Class) {
+        let props = class
+            .super_class
+            .as_deref()
+            .filter(|super_class| self.is_component_class(super_class))
+            .and(class.super_type_params.as_ref())
+            .and_then(|params| params.params.first())
+            .cloned();
+
+        self.class_props.push(props);
+    }
+
+    fn is_component_class(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => self.component_refs.contains(&ident.to_id()),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => {
+                matches!(&*prop.sym, "Component" | "PureComponent")
+                    && matches!(&**obj, Expr::Ident(obj) if self.namespace_refs.contains(&obj.to_id()))
+            }
+            Expr::Paren(ParenExpr { expr, .. }) => self.is_component_class(expr),
+            _ => false,
+        }
+    }
+
+    pub(super) fn enter_function(&mut self) {
+        self.class_props.push(None);
+    }
+
+    pub(super) fn exit(&mut self) {
+        self.class_props.pop();
+    }
+
+    pub(super) fn child_shape(&self, expr: &Expr) -> Option<ChildShape> {
+        match expr {
+            Expr::Lit(Lit::Str(..)) | Expr::Lit(Lit::Num(..)) | Expr::Tpl(..) => {
+                Some(ChildShape::Text)
+            }
+            Expr::Paren(ParenExpr { expr, .. }) => self.child_shape(expr),
+            _ => self.shape_of_type(self.type_of_expr(expr)?, 0),
+        }
+    }
+
+    fn type_of_expr<'a>(&'a self, expr: &'a Expr) -> Option<&'a TsType> {
+        match expr {
+            Expr::Ident(ident) => self.bindings.get(&ident.to_id()).map(|ty| &**ty),
+            Expr::Paren(ParenExpr { expr, .. }) => self.type_of_expr(expr),
+            Expr::TsAs(TsAsExpr { type_ann, .. }) => Some(type_ann),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => {
+                if obj.is_this() && prop.sym == "props" {
+                    return self.class_props.last()?.as_deref();
+                }
+
+                self.member_type(self.type_of_expr(obj)?, &prop.sym, 0)
+            }
+            _ => None,
+        }
+    }
+
+    fn member_type<'a>(&'a self, ty: &'a TsType, name: &Atom, depth: usize) -> Option<&'a TsType> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return None;
+        }
+
+        match ty {
+            TsType::TsTypeLit(TsTypeLit { members, .. }) => find_member(members, name),
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => {
+                self.member_type(type_ann, name, depth + 1)
+            }
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                TsIntersectionType { types, .. },
+            )) => types
+                .iter()
+                .find_map(|ty| self.member_type(ty, name, depth + 1)),
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(type_name),
+                ..
+            }) => match self.decls.get(&type_name.sym)? {
+                TypeDecl::Interface(members) => find_member(members, name),
+                TypeDecl::Alias(ty) => self.member_type(ty, name, depth + 1),
+            },
+            _ => None,
+        }
+    }
+
+    fn shape_of_type(&self, ty: &TsType, depth: usize) -> Option<ChildShape> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return None;
+        }
+
+        match ty {
+            TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsStringKeyword | TsKeywordTypeKind::TsNumberKeyword,
+                ..
+            }) => Some(ChildShape::Text),
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::Str(..) | TsLit::Number(..) | TsLit::Tpl(..),
+                ..
+            }) => Some(ChildShape::Text),
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => {
+                self.shape_of_type(type_ann, depth + 1)
+            }
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+                TsUnionType { types, .. },
+            )) => {
+                let mut shapes = types.iter().map(|ty| self.shape_of_type(ty, depth + 1));
+                let first = shapes.next()??;
+
+                shapes.all(|shape| shape == Some(first)).then_some(first)
+            }
+            TsType::TsTypeRef(TsTypeRef {
+                type_name,
+                type_params: None,
+                ..
+            }) => match type_name {
+                TsEntityName::Ident(type_name) => match self.decls.get(&type_name.sym) {
+                    Some(TypeDecl::Alias(ty)) => self.shape_of_type(ty, depth + 1),
+                    Some(TypeDecl::Interface(..)) => None,
+                    None => (type_name.sym == "VNode").then_some(ChildShape::VNode),
+                },
+                TsEntityName::TsQualifiedName(name) => match (&name.left, &*name.right.sym) {
+                    (TsEntityName::Ident(ns), "Element") if ns.sym == "JSX" => {
+                        Some(ChildShape::VNode)
+                    }
+                    (TsEntityName::Ident(ns), "VNode") if ns.sym == "Inferno" => {
+                        Some(ChildShape::VNode)
+                    }
+                    _ => None,
+                },
+                #[cfg(swc_ast_unknown)]
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn bind_pat(&mut self, pat: &Pat, ty: Option<&TsType>) {
+        match pat {
+            Pat::Ident(BindingIdent { id, type_ann }) => {
+                let ty = type_ann.as_ref().map(|ann| &*ann.type_ann).or(ty);
+
+                if let Some(ty) = ty {
+                    self.bindings.insert(id.to_id(), Box::new(ty.clone()));
+                }
+            }
+            Pat::Object(ObjectPat {
+                props, type_ann, ..
+            }) => {
+                let Some(ty) = type_ann.as_ref().map(|ann| &*ann.type_ann).or(ty) else {
+                    return;
+                };
+
+                let mut members = Vec::with_capacity(props.len());
+                for prop in props {
+                    match prop {
+                        ObjectPatProp::KeyValue(KeyValuePatProp {
+                            key: PropName::Ident(key),
+                            value,
+                        }) => {
+                            if let Some(member) = self.member_type(ty, &key.sym, 0) {
+                                members.push((&**value, member.clone()));
+                            }
+                        }
+                        ObjectPatProp::Assign(AssignPatProp { key, .. }) => {
+                            if let Some(member) = self.member_type(ty, &key.sym, 0).cloned() {
+                                self.bindings.insert(key.to_id(), Box::new(member));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                for (value, member) in members {
+                    self.bind_pat(value, Some(&member));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn find_member<'a>(members: &'a [TsTypeElement], name: &Atom) -> Option<&'a TsType> {
+    members.iter().find_map(|member| match member {
+        TsTypeElement::TsPropertySignature(TsPropertySignature {
+            key,
+            computed: false,
+            // Optional members may be `undefined`, which is not a valid text child
+            optional: false,
+            type_ann: Some(type_ann),
+            ..
+        }) => match &**key {
+            Expr::Ident(key) if key.sym == *name => Some(&*type_ann.type_ann),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+pub(super) struct TypeCollector {
+    scope: TypeScope,
+    import_source: Wtf8Atom,
+}
+
+impl Visit for TypeCollector {
+    fn visit_module(&mut self, module: &Module) {
+        // Imports are hoisted, so they are collected before any class is entered
+        for item in &module.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            if import.type_only || import.src.value != self.import_source {
+                continue;
+            }
+
+            for specifier in &import.specifiers {
+                match specifier {
+                    ImportSpecifier::Named(named) if !named.is_type_only => {
+                        if matches!(&*imported_name(named), "Component" | "PureComponent") {
+                            self.scope.component_refs.insert(named.local.to_id());
+                        }
+                    }
+                    ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })
+                    | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                        self.scope.namespace_refs.insert(local.to_id());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        module.visit_children_with(self);
+    }
+
+    fn visit_ts_interface_decl(&mut self, decl: &TsInterfaceDecl) {
+        self.scope.decls.insert(
+            decl.id.sym.clone(),
+            TypeDecl::Interface(decl.body.body.clone()),
+        );
+    }
+
+    fn visit_ts_type_alias_decl(&mut self, decl: &TsTypeAliasDecl) {
+        self.scope
+            .decls
+            .insert(decl.id.sym.clone(), TypeDecl::Alias(decl.type_ann.clone()));
+    }
+
+    fn visit_class(&mut self, class: &Class) {
+        self.scope.enter_class(class);
+        class.visit_children_with(self);
+        self.scope.exit();
+    }
+
+    fn visit_fn_decl(&mut self, decl: &FnDecl) {
+        self.scope.enter_function();
+        decl.visit_children_with(self);
+        self.scope.exit();
+    }
+
+    fn visit_fn_expr(&mut self, expr: &FnExpr) {
+        self.scope.enter_function();
+        expr.visit_children_with(self);
+        self.scope.exit();
+    }
+
+    fn visit_param(&mut self, param: &Param) {
+        self.scope.bind_pat(&param.pat, None);
+        param.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        for param in &arrow.params {
+            self.scope.bind_pat(param, None);
+        }
+        arrow.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        let init = declarator
+            .init
+            .as_deref()
+            .and_then(|init| self.scope.type_of_expr(init))
+            .cloned();
+
+        self.scope.bind_pat(&declarator.name, init.as_ref());
+        declarator.visit_children_with(self);
+    }
+}
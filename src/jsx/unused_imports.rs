@@ -0,0 +1,123 @@
+use rustc_hash::FxHashSet;
+use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::swc_atoms::Wtf8Atom;
+use swc_core::ecma::visit::{Visit, VisitWith, noop_visit_type};
+
+/// Bindings which only exist for the sake of JSX and may become unused once
+/// it is lowered.
+#[derive(Default)]
+pub(super) struct JsxOnlyRefs {
+    /// Roots of JSX element names, `Fragment` in `<Fragment>` or `Inferno` in
+    /// `<Inferno.Fragment>`
+    names: FxHashSet<Id>,
+    has_jsx: bool,
+    /// Imported name of a function whose calls have been compiled away
+    compiled_call: Option<&'static str>,
+}
+
+impl JsxOnlyRefs {
+    pub(super) fn collect(module: &Module) -> Self {
+        let mut refs = JsxOnlyRefs::default();
+        module.visit_with(&mut refs);
+        refs
+    }
+
+    /// Only the import of `imported`, once its calls have been compiled
+    pub(super) fn compiled_call(imported: &'static str) -> Self {
+        JsxOnlyRefs {
+            compiled_call: Some(imported),
+            ..Default::default()
+        }
+    }
+
+    fn is_candidate(&self, imported: &str, local: &Ident) -> bool {
+        self.names.contains(&local.to_id())
+            // Classic runtime JSX requires `createElement` to be in scope
+            || (self.has_jsx && imported == "createElement")
+            || self.compiled_call == Some(imported)
+    }
+}
+
+impl Visit for JsxOnlyRefs {
+    noop_visit_type!();
+
+    fn visit_jsx_element_name(&mut self, name: &JSXElementName) {
+        match name {
+            JSXElementName::Ident(ident) => {
+                self.names.insert(ident.to_id());
+            }
+            JSXElementName::JSXMemberExpr(member) => {
+                let mut obj = &member.obj;
+                while let JSXObject::JSXMemberExpr(member) = obj {
+                    obj = &member.obj;
+                }
+
+                if let JSXObject::Ident(ident) = obj {
+                    self.names.insert(ident.to_id());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_jsx_element(&mut self, el: &JSXElement) {
+        self.has_jsx = true;
+        el.visit_children_with(self);
+    }
+
+    fn visit_jsx_fragment(&mut self, frag: &JSXFragment) {
+        self.has_jsx = true;
+        frag.visit_children_with(self);
+    }
+}
+
+/// Removes specifiers of `src` imports which were only needed by JSX and are
+/// no longer referenced, dropping declarations which end up empty.
+pub(super) fn remove_unused_imports(
+    items: &mut Vec<ModuleItem>,
+    src: &Wtf8Atom,
+    refs: &JsxOnlyRefs,
+) {
+    let mut used = UsedIdents::default();
+    for item in items.iter() {
+        if !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(..))) {
+            item.visit_with(&mut used);
+        }
+    }
+
+    items.retain_mut(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+            return true;
+        };
+
+        if import.src.value != *src || import.specifiers.is_empty() {
+            return true;
+        }
+
+        import.specifiers.retain(|specifier| {
+            let (imported, local) = match specifier {
+                ImportSpecifier::Named(named) => (super::imported_name(named), &named.local),
+                ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                    (local.sym.clone(), local)
+                }
+                #[cfg(swc_ast_unknown)]
+                _ => return true,
+            };
+
+            !refs.is_candidate(&imported, local) || used.0.contains(&local.to_id())
+        });
+
+        !import.specifiers.is_empty()
+    });
+}
+
+/// Every identifier reference, including the ones in type positions
+#[derive(Default)]
+struct UsedIdents(FxHashSet<Id>);
+
+impl Visit for UsedIdents {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.0.insert(ident.to_id());
+    }
+}
@@ -30,6 +30,8 @@ pub(super) struct CreateVNodeArgs {
     pub(super) props: ObjectLit,
     pub(super) key: Option<ExprOrSpread>,
     pub(super) refs: Option<ExprOrSpread>,
+    /// Emit the trailing default arguments too
+    pub(super) define_all_arguments: bool,
 }
 
 impl CreateVNodeArgs {
@@ -45,6 +47,7 @@ impl CreateVNodeArgs {
             props,
             key,
             refs,
+            define_all_arguments,
         } = self;
 
         let mut args: Vec<ExprOrSpread> = Vec::with_capacity(8);
@@ -56,7 +59,8 @@ impl CreateVNodeArgs {
             || child_flags != (ChildFlags::HasInvalidChildren as u16);
         let has_props = !props.props.is_empty();
         let has_key = key.is_some();
-        let has_ref = refs.is_some();
+        // With all arguments defined the `ref` is always passed, which pads every argument before it
+        let has_ref = refs.is_some() || define_all_arguments;
 
         match class_name {
             None => {
@@ -78,6 +82,18 @@ impl CreateVNodeArgs {
             1 => {
                 let only_child = children.take().into_iter().next().flatten();
                 match only_child {
+                    // {...items} still has to be copied to an array
+                    Some(
+                        child @ ExprOrSpread {
+                            spread: Some(..), ..
+                        },
+                    ) => args.push(
+                        Box::new(Expr::Array(ArrayLit {
+                            span: DUMMY_SP,
+                            elems: vec![Some(child)],
+                        }))
+                        .as_arg(),
+                    ),
                     Some(child) => args.push(child.expr.as_arg()),
                     None => args.push(
                         Box::new(Expr::Array(ArrayLit {
@@ -125,8 +141,10 @@ impl CreateVNodeArgs {
             }
         }
 
-        if let Some(some_refs) = refs {
-            args.push(some_refs);
+        match refs {
+            Some(some_refs) => args.push(some_refs),
+            None if has_ref => args.push(null_arg()),
+            None => {}
         }
 
         args
@@ -140,13 +158,16 @@ pub(super) fn create_component_vnode_args(
     props_literal: ObjectLit,
     key: Option<ExprOrSpread>,
     refs: Option<ExprOrSpread>,
+    define_all_arguments: bool,
 ) -> Vec<ExprOrSpread> {
     let mut args: Vec<ExprOrSpread> = Vec::with_capacity(5);
     args.push(flags);
     args.push(name.as_arg());
 
+    let has_ref = refs.is_some() || define_all_arguments;
+
     if props_literal.props.is_empty() {
-        if key.is_some() || refs.is_some() {
+        if key.is_some() || has_ref {
             args.push(null_arg());
         }
     } else {
@@ -155,7 +176,7 @@ pub(super) fn create_component_vnode_args(
 
     match key {
         None => {
-            if refs.is_some() {
+            if has_ref {
                 args.push(null_arg());
             }
         }
@@ -164,8 +185,10 @@ pub(super) fn create_component_vnode_args(
         }
     }
 
-    if let Some(some_ref) = refs {
-        args.push(some_ref);
+    match refs {
+        Some(some_ref) => args.push(some_ref),
+        None if has_ref => args.push(null_arg()),
+        None => {}
     }
 
     args
@@ -178,11 +201,12 @@ pub(super) fn create_fragment_vnode_args(
     child_flags: u16,
     child_flags_override_param: Option<ExprOrSpread>,
     key: Option<ExprOrSpread>,
+    define_all_arguments: bool,
 ) -> Vec<ExprOrSpread> {
     let mut args: Vec<ExprOrSpread> = Vec::with_capacity(3);
     let has_child_flags = child_flags_override_param.is_some()
         || child_flags != (ChildFlags::HasInvalidChildren as u16);
-    let has_key = key.is_some();
+    let has_key = key.is_some() || define_all_arguments;
 
     match children.len() {
         0 => {
@@ -194,6 +218,17 @@ pub(super) fn create_fragment_vnode_args(
             if children_shape_is_user_defined || child_flags == ChildFlags::UnknownChildren as u16 {
                 let only_child = children.take().into_iter().next().flatten();
                 match only_child {
+                    Some(
+                        child @ ExprOrSpread {
+                            spread: Some(..), ..
+                        },
+                    ) => args.push(
+                        Box::new(Expr::Array(ArrayLit {
+                            span: DUMMY_SP,
+                            elems: vec![Some(child)],
+                        }))
+                        .as_arg(),
+                    ),
                     Some(child) => args.push(child.expr.as_arg()),
                     None => args.push(
                         Box::new(Expr::Array(ArrayLit {
@@ -233,8 +268,10 @@ pub(super) fn create_fragment_vnode_args(
         args.push(u16_as_num_arg(ChildFlags::HasInvalidChildren as u16));
     }
 
-    if let Some(some_key) = key {
-        args.push(some_key);
+    match key {
+        Some(some_key) => args.push(some_key),
+        None if has_key => args.push(null_arg()),
+        None => {}
     }
 
     args
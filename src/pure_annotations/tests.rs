@@ -131,8 +131,8 @@ test!(
   const x = <div />;
   "#,
     r#"
-import Inferno, { createVNode } from 'inferno';
-const x = /*#__PURE__*/ createVNode(1, "div");
+import Inferno from 'inferno';
+const x = /*#__PURE__*/ Inferno.createVNode(1, "div");
   "#
 );
 
@@ -140,12 +140,13 @@ test!(
     create_element_fragment_jsx,
     r#"
   import Inferno from 'inferno';
-  const x = <><div /></>;
+  const x = <><div /><span /></>;
   "#,
     r#"
-    import Inferno, { createVNode, createFragment } from 'inferno';
-    const x = /*#__PURE__*/ createFragment([
-        /*#__PURE__*/ createVNode(1, "div")
+    import Inferno from 'inferno';
+    const x = /*#__PURE__*/ Inferno.createFragment([
+        /*#__PURE__*/ Inferno.createVNode(1, "div"),
+        /*#__PURE__*/ Inferno.createVNode(1, "span")
     ], 4);
   "#
 );
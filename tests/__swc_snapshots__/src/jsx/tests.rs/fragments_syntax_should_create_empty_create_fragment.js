@@ -1,2 +1 @@
-import { createFragment } from "inferno";
-/*#__PURE__*/ createFragment();
+null;
@@ -1,2 +1,2 @@
-import Inferno, { createVNode } from 'inferno';
-/*#__PURE__*/ createVNode(1, "div");
+import Inferno from 'inferno';
+/*#__PURE__*/ Inferno.createVNode(1, "div");
@@ -1,4 +1,4 @@
-import Inferno, { createVNode } from 'inferno';
-/*#__PURE__*/ createVNode(1, "div", null, "Hello World", 16, {
+import Inferno from 'inferno';
+/*#__PURE__*/ Inferno.createVNode(1, "div", null, "Hello World", 16, {
     style: "white-space: pre"
 });
@@ -1,11 +1,7 @@
-import { createVNode, createFragment } from "inferno";
-/*#__PURE__*/ createVNode(1, "div", null, /*#__PURE__*/ createFragment([
-    /*#__PURE__*/ createFragment([
-        /*#__PURE__*/ createVNode(1, "span", null, "Hello", 16),
-        /*#__PURE__*/ createVNode(1, "span", null, "world", 16)
-    ], 4),
-    /*#__PURE__*/ createFragment([
-        /*#__PURE__*/ createVNode(1, "span", null, "Goodbye", 16),
-        /*#__PURE__*/ createVNode(1, "span", null, "world", 16)
-    ], 4)
-], 4), 2);
+import { createVNode } from "inferno";
+/*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "span", null, "Hello", 16),
+    /*#__PURE__*/ createVNode(1, "span", null, "world", 16),
+    /*#__PURE__*/ createVNode(1, "span", null, "Goodbye", 16),
+    /*#__PURE__*/ createVNode(1, "span", null, "world", 16)
+], 4);
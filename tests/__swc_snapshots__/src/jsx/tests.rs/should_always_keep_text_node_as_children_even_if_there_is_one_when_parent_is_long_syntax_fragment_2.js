@@ -1,6 +1,4 @@
 import { createTextVNode, createFragment } from "inferno";
 /*#__PURE__*/ createFragment([
-    /*#__PURE__*/ createFragment([
-        createTextVNode("Text")
-    ], 4)
+    createTextVNode("Text")
 ], 4);
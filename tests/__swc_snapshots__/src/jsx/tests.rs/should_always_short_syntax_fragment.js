@@ -1,6 +1,2 @@
-import { createVNode, createFragment } from "inferno";
-/*#__PURE__*/ createFragment([
-    /*#__PURE__*/ createFragment([
-        /*#__PURE__*/ createVNode(1, "div", null, "Text", 16)
-    ], 4)
-], 4);
+import { createVNode } from "inferno";
+/*#__PURE__*/ createVNode(1, "div", null, "Text", 16);
@@ -0,0 +1,3 @@
+import { createVNode } from "inferno";
+const shadowed = /*#__PURE__*/ createVNode(1, "ul", null, items.map((item)=>/*#__PURE__*/ createVNode(1, "li", null, item.tags.map((item)=>item.label), 0, null, item.id)), 8);
+const empty = /*#__PURE__*/ createVNode(1, "ul", null, null, 0);
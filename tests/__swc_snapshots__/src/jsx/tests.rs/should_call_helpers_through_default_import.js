@@ -0,0 +1,5 @@
+import Inferno from 'inferno';
+const el = /*#__PURE__*/ Inferno.createFragment([
+    /*#__PURE__*/ Inferno.createVNode(1, "div"),
+    /*#__PURE__*/ Inferno.createVNode(1, "span")
+], 4);
@@ -0,0 +1,2 @@
+const _inferno = /*#__PURE__*/ _interop_require_default(require("inferno"));
+var x = /*#__PURE__*/ _inferno.default.createVNode(1, "div", null, text, 0);
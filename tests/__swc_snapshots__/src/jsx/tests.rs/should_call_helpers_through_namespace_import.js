@@ -0,0 +1,7 @@
+import * as Inferno from 'inferno';
+Inferno.render(/*#__PURE__*/ Inferno.createVNode(1, "div", null, [
+    text,
+    /*#__PURE__*/ Inferno.normalizeProps(Inferno.createComponentVNode(2, Child, {
+        ...props
+    }))
+], 0), container);
@@ -0,0 +1,4 @@
+const Inferno = require('inferno');
+var x = /*#__PURE__*/ Inferno.createFragment([
+    /*#__PURE__*/ Inferno.createVNode(1, "div")
+], 4, "a");
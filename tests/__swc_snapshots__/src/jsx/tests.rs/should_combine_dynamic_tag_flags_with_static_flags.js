@@ -0,0 +1,6 @@
+import { createVNode, createComponentVNode, getFlagsForElementVnode } from "inferno";
+const a = /*#__PURE__*/ createVNode(getFlagsForElementVnode(props.tag) | 6144, props.tag, null, null, 1, {
+    contentEditable: true
+});
+const b = /*#__PURE__*/ createVNode(1, Tag);
+const c = /*#__PURE__*/ createComponentVNode(2, Tag);
@@ -0,0 +1,25 @@
+import { createVNode, createTextVNode, createFragment } from "inferno";
+const a = /*#__PURE__*/ createVNode(1, "ul", null, [
+    ...items.map((item)=>/*#__PURE__*/ createVNode(1, "li", null, item.name, 0, null, item.id))
+], 8);
+const b = /*#__PURE__*/ createVNode(1, "ul", null, [
+    ...items.map(function(item) {
+        return /*#__PURE__*/ createVNode(1, "li", null, item.name, 0);
+    })
+], 4);
+const c = /*#__PURE__*/ createVNode(1, "ul", null, [
+    /*#__PURE__*/ createVNode(1, "li", null, null, 1, null, "first"),
+    ...items.map((item)=>/*#__PURE__*/ createVNode(1, "li", null, null, 1, null, item.id)),
+    createTextVNode("text")
+], 4);
+const d = /*#__PURE__*/ createVNode(1, "ul", null, [
+    /*#__PURE__*/ createVNode(1, "li", null, null, 1, null, "a"),
+    /*#__PURE__*/ createVNode(1, "li", null, null, 1, null, "b")
+], 8);
+const e = /*#__PURE__*/ createFragment([
+    ...items.map((item)=>/*#__PURE__*/ createVNode(1, "li", null, null, 1, null, item.id))
+], 8);
+const f = /*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "b"),
+    ...items.map((item)=>/*#__PURE__*/ createVNode(1, "li", null, null, 1, null, item.id))
+], 4);
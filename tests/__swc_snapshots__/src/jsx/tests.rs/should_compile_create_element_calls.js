@@ -0,0 +1,15 @@
+import { createVNode, createComponentVNode, normalizeProps } from "inferno";
+const list = /*#__PURE__*/ createVNode(1, "ul", 'list', [
+    /*#__PURE__*/ createVNode(1, "li", null, "first", 16, null, 'a'),
+    /*#__PURE__*/ createVNode(1, "li", null, text, 0, null, 'b')
+], 8, {
+    "data-id": id
+});
+const component = /*#__PURE__*/ normalizeProps(createComponentVNode(2, Button, {
+    onClick: onClick,
+    ...rest,
+    children: "click"
+}));
+const element = /*#__PURE__*/ normalizeProps(createVNode(64, "input", null, null, 1, {
+    ...props
+}));
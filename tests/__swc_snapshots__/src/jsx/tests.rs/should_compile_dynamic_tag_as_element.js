@@ -0,0 +1,7 @@
+import { createVNode, getFlagsForElementVnode } from "inferno";
+function Box(props) {
+    const Tag = props.as || 'div';
+    return /*#__PURE__*/ createVNode(getFlagsForElementVnode(Tag), Tag, "box", props.children, 0, {
+        onClick: props.onClick
+    });
+}
@@ -0,0 +1,91 @@
+import { Header } from './header';
+function ssrMarkup(html) {
+    return {
+        __ssr: html,
+        toString: function() {
+            return html;
+        }
+    };
+}
+function ssrChild(value) {
+    if (value == null || typeof value === "boolean") return "";
+    if (Array.isArray(value)) {
+        var html = "";
+        var wasText = false;
+        for(var i = 0; i < value.length; i++){
+            var isText = typeof value[i] === "string" || typeof value[i] === "number";
+            if (isText && wasText) html += "<!---->";
+            html += ssrChild(value[i]);
+            wasText = isText;
+        }
+        return html;
+    }
+    if (typeof value === "object" && "__ssr" in value) return value.__ssr;
+    return ssrEscape(value);
+}
+function ssrEscape(value) {
+    return String(value).replace(/[&<>"']/g, function(c) {
+        return c === "&" ? "&amp;" : c === "<" ? "&lt;" : c === ">" ? "&gt;" : c === '"' ? "&quot;" : "&#039;";
+    });
+}
+function ssrAttr(name, value) {
+    if (value == null || value === false || typeof value === "function") return "";
+    return value === true ? " " + name : " " + name + '="' + ssrEscape(value) + '"';
+}
+function ssrStyle(value) {
+    if (value == null || typeof value !== "object") return value;
+    var css = "";
+    for(var name in value){
+        if (value[name] != null && value[name] !== "") css += name + ":" + value[name] + ";";
+    }
+    return css;
+}
+function ssrSpread(props) {
+    var html = "";
+    for(var name in props){
+        var value = props[name];
+        if (name === "children" || name === "key" || name === "ref" || name === "dangerouslySetInnerHTML" || /^on[A-Z]/.test(name)) continue;
+        if (name === "className") name = "class";
+        else if (name === "htmlFor") name = "for";
+        else if (name === "defaultValue") name = "value";
+        else if (name === "defaultChecked") name = "checked";
+        else if (name === "style") value = ssrStyle(value);
+        html += ssrAttr(name, value);
+    }
+    return html;
+}
+function ssrComponent(Component, props) {
+    var defaults = Component.defaultProps;
+    if (defaults) {
+        props = Object.assign({}, props);
+        for(var name in defaults){
+            if (props[name] === undefined) props[name] = defaults[name];
+        }
+    }
+    var rendered;
+    if (Component.prototype && Component.prototype.render) {
+        var instance = new Component(props, {});
+        instance.props = props;
+        if (Component.getDerivedStateFromProps) {
+            instance.state = Object.assign({}, instance.state, Component.getDerivedStateFromProps(props, instance.state));
+        } else if (instance.componentWillMount) {
+            instance.componentWillMount();
+        }
+        rendered = instance.render(props, instance.state, {});
+    } else {
+        rendered = Component(props, {});
+    }
+    return rendered == null || typeof rendered === "boolean" ? "<!--!-->" : ssrChild(rendered);
+}
+export function Page({ title, items, attrs }) {
+    return /*#__PURE__*/ ssrMarkup('<div class="page"' + ssrAttr("id", title) + ssrAttr("style", ssrStyle({
+        color: 'red'
+    })) + ssrSpread(attrs) + ">" + ssrComponent(Header, {
+        title: title,
+        children: /*#__PURE__*/ ssrMarkup("<span>Menu</span>")
+    }) + '<input type="text" disabled' + ssrAttr("value", title) + "><p>Hello <!---->" + ssrChild(title) + "<!---->, &quot;welcome&quot; &amp; bye</p><ul>" + ssrChild(items.map((item)=>/*#__PURE__*/ ssrMarkup("<li>" + ssrChild(item) + "</li>"))) + "</ul><textarea>" + ssrChild(title) + "</textarea><div>" + [
+        {
+            __html: title
+        }.__html
+    ].join("") + "</div></div>");
+}
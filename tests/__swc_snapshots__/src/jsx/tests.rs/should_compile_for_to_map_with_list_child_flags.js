@@ -0,0 +1,7 @@
+import { createVNode } from "inferno";
+const keyed = /*#__PURE__*/ createVNode(1, "ul", null, items.map((item, i)=>/*#__PURE__*/ createVNode(1, "li", null, i, 0, null, item.id)), 8);
+const nonKeyed = /*#__PURE__*/ createVNode(1, "ul", null, items.map((item)=>/*#__PURE__*/ createVNode(1, "li", null, item, 0)), 4);
+const nested = /*#__PURE__*/ createVNode(1, "ul", null, [
+    /*#__PURE__*/ createVNode(1, "li"),
+    items.map((item)=>/*#__PURE__*/ createVNode(1, "li", null, null, 1, null, item))
+], 0);
@@ -0,0 +1,52 @@
+function ssrMarkup(html) {
+    return {
+        __ssr: html,
+        toString: function() {
+            return html;
+        }
+    };
+}
+function ssrChild(value) {
+    if (value == null || typeof value === "boolean") return "";
+    if (Array.isArray(value)) {
+        var html = "";
+        var wasText = false;
+        for(var i = 0; i < value.length; i++){
+            var isText = typeof value[i] === "string" || typeof value[i] === "number";
+            if (isText && wasText) html += "<!---->";
+            html += ssrChild(value[i]);
+            wasText = isText;
+        }
+        return html;
+    }
+    if (typeof value === "object" && "__ssr" in value) return value.__ssr;
+    return ssrEscape(value);
+}
+function ssrComponent(Component, props) {
+    var defaults = Component.defaultProps;
+    if (defaults) {
+        props = Object.assign({}, props);
+        for(var name in defaults){
+            if (props[name] === undefined) props[name] = defaults[name];
+        }
+    }
+    var rendered;
+    if (Component.prototype && Component.prototype.render) {
+        var instance = new Component(props, {});
+        instance.props = props;
+        if (Component.getDerivedStateFromProps) {
+            instance.state = Object.assign({}, instance.state, Component.getDerivedStateFromProps(props, instance.state));
+        } else if (instance.componentWillMount) {
+            instance.componentWillMount();
+        }
+        rendered = instance.render(props, instance.state, {});
+    } else {
+        rendered = Component(props, {});
+    }
+    return rendered == null || typeof rendered === "boolean" ? "<!--!-->" : ssrChild(rendered);
+}
+const list = /*#__PURE__*/ ssrMarkup(ssrComponent(Item, {
+    children: (value)=>value
+}) + ssrComponent(Item, {
+    text: "a"
+}) + "text");
@@ -0,0 +1,20 @@
+import { createVNode, createComponentVNode, normalizeProps } from "inferno";
+import { Button } from './button';
+const card = /*#__PURE__*/ createVNode(1, "div", `card ${active}`, [
+    /*#__PURE__*/ createVNode(1, "h2", null, title, 0, {
+        id: "main"
+    }),
+    /*#__PURE__*/ normalizeProps(createVNode(64, "input", null, null, 1, {
+        disabled: true,
+        value: value,
+        ...rest
+    })),
+    /*#__PURE__*/ createComponentVNode(2, Button, {
+        children: "Ok"
+    }, "ok"),
+    /*#__PURE__*/ createComponentVNode(2, Ui.Icon, {
+        name: "star"
+    })
+], 8, {
+    onClick: onClick
+});
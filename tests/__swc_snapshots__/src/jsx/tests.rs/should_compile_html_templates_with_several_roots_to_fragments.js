@@ -0,0 +1,6 @@
+import { createVNode, createFragment } from "inferno";
+const list = /*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "li", null, "One", 16),
+    /*#__PURE__*/ createVNode(1, "li", null, two, 0)
+], 4);
+const other = tpl`<div />`;
@@ -0,0 +1,19 @@
+import { createVNode, createComponentVNode } from "inferno";
+const card = /*#__PURE__*/ createVNode(1, "div", "card is-active wide", [
+    /*#__PURE__*/ createVNode(1, "h2", "title", "Title", 16),
+    /*#__PURE__*/ createVNode(1, "p", null, text, 0),
+    /*#__PURE__*/ createComponentVNode(2, Button, {
+        children: "Ok"
+    }, 'ok')
+], 8, {
+    onClick: onClick,
+    id: "main"
+});
+const idProp = /*#__PURE__*/ createVNode(1, "div", null, null, 1, {
+    id: 'other'
+});
+const input = /*#__PURE__*/ createVNode(64, "input", null, null, 1, {
+    value: value
+});
+const zero = /*#__PURE__*/ createVNode(1, "div");
+const empty = /*#__PURE__*/ createVNode(1, "p");
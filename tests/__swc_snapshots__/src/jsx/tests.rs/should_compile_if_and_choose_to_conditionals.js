@@ -0,0 +1,11 @@
+import { createVNode, createComponentVNode, createTextVNode, createFragment } from "inferno";
+const a = /*#__PURE__*/ createVNode(1, "div", null, [
+    loggedIn ? /*#__PURE__*/ createComponentVNode(2, Avatar) : null,
+    count === 0 ? /*#__PURE__*/ createFragment([
+        createTextVNode("empty")
+    ], 4) : count === 1 ? /*#__PURE__*/ createVNode(1, "b", null, "one", 16) : /*#__PURE__*/ createFragment([
+        /*#__PURE__*/ createVNode(1, "i", null, "many", 16),
+        /*#__PURE__*/ createVNode(1, "i", null, "items", 16)
+    ], 4)
+], 0);
+const b = visible ? /*#__PURE__*/ createVNode(1, "span") : null;
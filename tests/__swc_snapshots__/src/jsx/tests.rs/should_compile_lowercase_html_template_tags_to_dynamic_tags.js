@@ -0,0 +1,7 @@
+import { createVNode, getFlagsForElementVnode } from "inferno";
+const heading = (comp)=>/*#__PURE__*/ createVNode(1, "section", null, [
+        /*#__PURE__*/ createVNode(getFlagsForElementVnode(comp), comp, null, null, 1, {
+            x: 1
+        }),
+        /*#__PURE__*/ createVNode(getFlagsForElementVnode(props.tag), props.tag, "title", title, 0)
+    ], 4);
@@ -0,0 +1,6 @@
+import { createVNode, createComponentVNode, createTextVNode, createFragment, createPortal } from 'inferno';
+const single = /*#__PURE__*/ createVNode(1, "div", null, /*#__PURE__*/ createPortal(/*#__PURE__*/ createComponentVNode(2, Modal), document.body), 2);
+const many = /*#__PURE__*/ createPortal(createFragment([
+    /*#__PURE__*/ createVNode(1, "b"),
+    createTextVNode("text")
+], 4), el);
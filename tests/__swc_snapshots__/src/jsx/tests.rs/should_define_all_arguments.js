@@ -0,0 +1,13 @@
+import { createVNode, createComponentVNode, createFragment } from "inferno";
+const a = /*#__PURE__*/ createVNode(1, "div", null, null, 1, null, null, null);
+const b = /*#__PURE__*/ createVNode(1, "div", "box", "text", 16, null, null, null);
+const c = /*#__PURE__*/ createVNode(1, "li", null, item.name, 0, null, item.id, setRef);
+const d = /*#__PURE__*/ createComponentVNode(2, Item, null, null, null);
+const e = /*#__PURE__*/ createComponentVNode(2, Item, {
+    title: title
+}, "a", null);
+const f = /*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "span", null, null, 1, null, null, null),
+    /*#__PURE__*/ createVNode(1, "span", null, null, 1, null, null, null)
+], 4, null);
+const g = /*#__PURE__*/ createFragment(items, 0, "b");
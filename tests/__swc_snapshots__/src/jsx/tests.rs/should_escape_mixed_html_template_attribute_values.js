@@ -0,0 +1,5 @@
+import { createVNode } from "inferno";
+const a = /*#__PURE__*/ createVNode(1, "div", null, null, 1, {
+    title: `C:\\new ${x}`,
+    "data-tpl": `\${y} \` ${z}`
+});
@@ -0,0 +1,3 @@
+"use strict";
+const { render, createVNode, createComponentVNode } = require("inferno");
+render(/*#__PURE__*/ createVNode(1, "div", null, /*#__PURE__*/ createComponentVNode(2, Child), 2), container);
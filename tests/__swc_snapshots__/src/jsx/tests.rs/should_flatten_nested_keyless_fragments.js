@@ -0,0 +1,20 @@
+import { createVNode, createComponentVNode, createFragment } from "inferno";
+/*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "a"),
+    /*#__PURE__*/ createVNode(1, "b"),
+    /*#__PURE__*/ createVNode(1, "i"),
+    x,
+    /*#__PURE__*/ createFragment([
+        /*#__PURE__*/ createVNode(1, "u")
+    ], 4, "k")
+], 0);
+/*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "a"),
+    /*#__PURE__*/ createVNode(1, "b")
+], 4);
+/*#__PURE__*/ createComponentVNode(2, Com, {
+    children: /*#__PURE__*/ createFragment([
+        /*#__PURE__*/ createVNode(1, "a"),
+        /*#__PURE__*/ createVNode(1, "b")
+    ], 4)
+});
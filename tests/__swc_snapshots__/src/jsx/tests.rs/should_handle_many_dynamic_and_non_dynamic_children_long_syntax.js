@@ -1,8 +1,6 @@
 import { createVNode, createTextVNode, createFragment } from "inferno";
 /*#__PURE__*/ createFragment([
-    /*#__PURE__*/ createFragment([
-        /*#__PURE__*/ createVNode(1, "span"),
-        createTextVNode("Text"),
-        Wohoo
-    ], 0)
-], 4);
+    /*#__PURE__*/ createVNode(1, "span"),
+    createTextVNode("Text"),
+    Wohoo
+], 0);
@@ -1,8 +1,6 @@
 import { createTextVNode, createFragment } from "inferno";
 /*#__PURE__*/ createFragment([
-    /*#__PURE__*/ createFragment([
-        Frag,
-        createTextVNode("Text"),
-        Wohoo
-    ], 0)
-], 4);
+    Frag,
+    createTextVNode("Text"),
+    Wohoo
+], 0);
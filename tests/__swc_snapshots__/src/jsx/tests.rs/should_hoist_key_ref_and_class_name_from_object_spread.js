@@ -0,0 +1,17 @@
+import { createVNode, createComponentVNode, normalizeProps } from "inferno";
+/*#__PURE__*/ createVNode(1, "div", 'row', null, 1, {
+    title
+}, id, rowRef);
+/*#__PURE__*/ createVNode(1, "div", 'b');
+/*#__PURE__*/ createVNode(1, "div", "a", null, 1, null, 2);
+/*#__PURE__*/ createComponentVNode(2, Com, {
+    className: 'row'
+}, id, ref);
+/*#__PURE__*/ normalizeProps(createVNode(1, "div", null, null, 1, {
+    className: 'a',
+    ...rest
+}));
+/*#__PURE__*/ normalizeProps(createVNode(1, "div", 'b', null, 1, {
+    className: 'a',
+    ...rest
+}));
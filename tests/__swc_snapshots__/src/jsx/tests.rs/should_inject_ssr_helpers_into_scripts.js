@@ -0,0 +1,30 @@
+function ssrMarkup(html) {
+    return {
+        __ssr: html,
+        toString: function() {
+            return html;
+        }
+    };
+}
+function ssrChild(value) {
+    if (value == null || typeof value === "boolean") return "";
+    if (Array.isArray(value)) {
+        var html = "";
+        var wasText = false;
+        for(var i = 0; i < value.length; i++){
+            var isText = typeof value[i] === "string" || typeof value[i] === "number";
+            if (isText && wasText) html += "<!---->";
+            html += ssrChild(value[i]);
+            wasText = isText;
+        }
+        return html;
+    }
+    if (typeof value === "object" && "__ssr" in value) return value.__ssr;
+    return ssrEscape(value);
+}
+function ssrEscape(value) {
+    return String(value).replace(/[&<>"']/g, function(c) {
+        return c === "&" ? "&amp;" : c === "<" ? "&lt;" : c === ">" ? "&gt;" : c === '"' ? "&quot;" : "&#039;";
+    });
+}
+const el = /*#__PURE__*/ ssrMarkup("<div>" + ssrChild(x) + "</div>");
@@ -0,0 +1,21 @@
+import { createVNode } from "inferno";
+const Label = ({ text, kind: type })=>/*#__PURE__*/ createVNode(1, "span", type, text, 0);
+function Icon(props) {
+    return /*#__PURE__*/ createVNode(1, "i", "icon-" + props.name);
+}
+const Button = ({ label, onClick })=>/*#__PURE__*/ createVNode(1, "button", null, [
+        /*#__PURE__*/ createVNode(1, "i", "icon-" + "ok"),
+        /*#__PURE__*/ createVNode(1, "span", void 0, label, 0)
+    ], 4, {
+        onClick: onClick
+    });
+const app = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "span", "title", "Hello", 0, null, "a"),
+    /*#__PURE__*/ createVNode(1, "span", void 0, user.name, 0),
+    /*#__PURE__*/ createVNode(1, "button", null, [
+        createVNode(1, "i", "icon-" + "ok"),
+        createVNode(1, "span", void 0, t("ok"), 0)
+    ], 4, {
+        onClick: save
+    })
+], 8);
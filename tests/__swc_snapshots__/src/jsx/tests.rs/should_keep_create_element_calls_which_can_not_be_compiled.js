@@ -0,0 +1,12 @@
+import { createVNode, createComponentVNode } from "inferno";
+import * as CE from 'inferno-create-element';
+const dynamic = CE.createElement(tag, null);
+const member = CE.createElement(props.tag, null);
+const memberComponent = /*#__PURE__*/ createComponentVNode(2, UI.Button);
+const renamed = CE.createElement('label', {
+    htmlFor: 'name'
+});
+const computed = CE.createElement('div', {
+    [name]: value
+});
+const nested = /*#__PURE__*/ createVNode(1, "div", null, /*#__PURE__*/ createComponentVNode(2, Component), 2);
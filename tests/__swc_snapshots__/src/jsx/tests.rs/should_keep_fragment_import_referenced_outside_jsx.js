@@ -0,0 +1,6 @@
+import { Fragment, createElement, createVNode, createFragment } from 'inferno';
+const el = /*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "div")
+], 4, "a");
+export { Fragment };
+export const legacy = createElement('div');
@@ -0,0 +1,17 @@
+import { createComponentVNode } from "inferno";
+import { h } from 'inferno-hyperscript';
+const dynamicClass = h('div.card', {
+    className: cls
+});
+const spread = h('div#main', props, 'text');
+const ambiguous = h('div', propsOrChildren);
+const selector = h('div ' + name);
+const hooks = h(Child, {
+    hooks
+});
+const member = h(props.tag, {
+    id
+});
+const memberComponent = /*#__PURE__*/ createComponentVNode(2, UI.Button, {
+    id: id
+});
@@ -0,0 +1,45 @@
+import { createVNode, createComponentVNode, normalizeProps } from "inferno";
+export function Exported({ size }) {
+    return /*#__PURE__*/ createVNode(1, "span", null, size, 0);
+}
+Exported.defaultProps = {
+    size: 'm'
+};
+const Icon = ({ name, size })=>/*#__PURE__*/ createVNode(1, "i", name + size);
+Icon.defaultProps = {
+    size: 16,
+    style: {}
+};
+const Label = ({ text })=>/*#__PURE__*/ createVNode(1, "span", null, text, 0);
+Label.defaultProps = {
+    text: "label"
+};
+const Spread = ({ text })=>/*#__PURE__*/ createVNode(1, "span", null, text, 0);
+Spread.defaultProps = {
+    text: "spread"
+};
+const Mapped = ({ text })=>/*#__PURE__*/ createVNode(1, "span", null, text, 0);
+Mapped.defaultProps = {
+    text: "mapped"
+};
+const components = [
+    Mapped
+];
+const app = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createComponentVNode(2, Exported, {
+        size: 'm'
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Icon, {
+        name: "star",
+        size: 16
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Label, {
+        text: maybeText
+    }),
+    /*#__PURE__*/ normalizeProps(createComponentVNode(2, Spread, {
+        ...props
+    })),
+    /*#__PURE__*/ createComponentVNode(2, Mapped, {
+        text: "mapped"
+    })
+], 4);
@@ -0,0 +1,19 @@
+import { createVNode } from "inferno";
+const a = /*#__PURE__*/ createVNode(256, "select", null, [
+    /*#__PURE__*/ createVNode(1, "option", null, "Small", 16, {
+        value: "s"
+    }),
+    /*#__PURE__*/ createVNode(1, "option", null, "Medium", 16, {
+        value: "m",
+        selected: true
+    }),
+    /*#__PURE__*/ createVNode(1, "optgroup", null, sizes.map((size1)=>/*#__PURE__*/ createVNode(1, "option", null, size1, 0, {
+            value: size1,
+            selected: size1 === "l"
+        })), 0, {
+        label: "Large"
+    })
+], 4, {
+    value: size,
+    onChange: onChange
+});
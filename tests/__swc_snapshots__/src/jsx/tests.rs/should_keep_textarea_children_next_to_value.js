@@ -0,0 +1,7 @@
+import { createVNode } from "inferno";
+const a = /*#__PURE__*/ createVNode(128, "textarea", null, "Ignored", 16, {
+    value: text
+});
+const b = /*#__PURE__*/ createVNode(128, "textarea", null, ignored, 0, {
+    defaultValue: "draft"
+});
@@ -0,0 +1,52 @@
+import { createVNode } from "inferno";
+function createTemplate(html, slots) {
+    return {
+        html: html,
+        slots: slots,
+        content: null
+    };
+}
+function cloneTemplate(template, values) {
+    return function(dom) {
+        if (dom == null) return;
+        var last = dom.$tv;
+        if (dom.$t !== template) {
+            if (template.content == null) {
+                var el = document.createElement("template");
+                el.innerHTML = template.html;
+                template.content = el.content;
+            }
+            dom.textContent = "";
+            dom.appendChild(template.content.cloneNode(true));
+            var nodes = [];
+            for(var i = 0; i < template.slots.length; i++){
+                var node = dom;
+                for(var j = 0; j < template.slots[i].length - 1; j++){
+                    node = node.childNodes[template.slots[i][j]];
+                }
+                nodes.push(node);
+            }
+            dom.$t = template;
+            dom.$tn = nodes;
+            last = null;
+        }
+        for(var k = 0; k < values.length; k++){
+            var value = values[k];
+            if (last && last[k] === value) continue;
+            var slot = template.slots[k];
+            var name = slot[slot.length - 1];
+            if (name === null) {
+                dom.$tn[k].textContent = value == null || typeof value === "boolean" ? "" : value;
+            } else if (value == null || value === false) {
+                dom.$tn[k].removeAttribute(name);
+            } else {
+                dom.$tn[k].setAttribute(name, value === true ? "" : value);
+            }
+        }
+        dom.$tv = values;
+    };
+}
+const template = createTemplate("<li>a</li><li>b</li><li>c</li>", []);
+const template1 = createTemplate("<li>a</li><li>b</li><li>c</li>", []);
+const list = cond ? /*#__PURE__*/ createVNode(1, "ul", null, null, 1, null, "$tc5ba8d80", cloneTemplate(template, [])) : /*#__PURE__*/ createVNode(1, "ul", null, items, 0);
+const keyed = /*#__PURE__*/ createVNode(1, "ul", null, null, 1, null, id + "$tc5ba8d80", cloneTemplate(template1, []));
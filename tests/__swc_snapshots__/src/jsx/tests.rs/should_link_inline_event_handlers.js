@@ -0,0 +1,24 @@
+import { createVNode, linkEvent } from "inferno";
+import { select } from './store';
+const handleMouseEnter = (item, event)=>highlight(item, event);
+const handleClick = (store)=>{
+    store.save();
+};
+const handleClick1 = (row)=>select(row.id);
+class List extends Component {
+    render() {
+        const handleClick = (item)=>this.select(item.id);
+        return /*#__PURE__*/ createVNode(1, "ul", null, this.props.items.map((item)=>/*#__PURE__*/ createVNode(1, "li", null, item.name, 0, {
+                onClick: linkEvent(item, handleClick),
+                onMouseEnter: linkEvent(item, handleMouseEnter)
+            })), 0);
+    }
+}
+function Toolbar({ store }) {
+    return /*#__PURE__*/ createVNode(1, "button", null, "Save", 16, {
+        onClick: linkEvent(store, handleClick)
+    });
+}
+const Row = ({ row })=>/*#__PURE__*/ createVNode(1, "tr", null, null, 1, {
+        onClick: linkEvent(row, handleClick1)
+    });
@@ -0,0 +1,27 @@
+import { createVNode, createComponentVNode } from "inferno";
+const Button = ({ size, kind, label })=>/*#__PURE__*/ createVNode(1, "button", size + kind, label, 0);
+class Card extends Component {
+    render() {
+        return /*#__PURE__*/ createVNode(1, "div", null, this.props.title, 0);
+    }
+}
+const app = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createComponentVNode(2, Button, {
+        label: "Save",
+        size: 'm',
+        kind: "primary"
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Button, {
+        label: "Big",
+        size: "l",
+        kind: "primary"
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Card, {
+        elevation: 1,
+        title: `Untitled`
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Card, {
+        title: /*#__PURE__*/ createVNode(1, "b", null, "Bold", 16),
+        elevation: -1
+    })
+], 4);
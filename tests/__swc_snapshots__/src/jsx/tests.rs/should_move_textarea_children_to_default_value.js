@@ -0,0 +1,22 @@
+import { createVNode, createTextVNode } from "inferno";
+const a = /*#__PURE__*/ createVNode(128, "textarea", "note", text, 0);
+const b = /*#__PURE__*/ createVNode(128, "textarea", null, [
+    count,
+    createTextVNode(" items")
+], 0, {
+    name: "count"
+});
+const c = /*#__PURE__*/ createVNode(128, "textarea", null, [
+    first,
+    second
+], 0);
+const d = /*#__PURE__*/ createVNode(128, "textarea");
+const e = /*#__PURE__*/ createVNode(128, "textarea", null, /*#__PURE__*/ createVNode(1, "b", null, "markup", 16), 2);
+const f = /*#__PURE__*/ createVNode(128, "textarea", null, null, 1, {
+    defaultValue: `${count} items`
+});
+const g = /*#__PURE__*/ createVNode(128, "textarea", null, [
+    createTextVNode("Hello "),
+    name
+], 0);
+const h = /*#__PURE__*/ createVNode(128, "textarea", null, ok && "x", 0);
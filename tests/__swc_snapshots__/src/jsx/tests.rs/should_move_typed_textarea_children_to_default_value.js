@@ -0,0 +1,12 @@
+const { createVNode } = require("inferno");
+interface Props {
+    name: string;
+    count: number;
+    note?: string;
+}
+const Form = ({ name, count, note }: Props)=>/*#__PURE__*/ createVNode(1, "form", null, [
+        /*#__PURE__*/ createVNode(128, "textarea", null, null, 1, {
+            defaultValue: "Hello " + name + ", " + count + " items"
+        }),
+        /*#__PURE__*/ createVNode(128, "textarea", null, note, 0)
+    ], 4);
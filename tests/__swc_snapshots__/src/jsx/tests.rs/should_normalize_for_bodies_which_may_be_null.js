@@ -0,0 +1,8 @@
+import { createVNode, createFragment } from "inferno";
+const conditional = /*#__PURE__*/ createVNode(1, "ul", null, xs.map((x)=>x.ok ? /*#__PURE__*/ createVNode(1, "li", null, null, 1, null, x.id) : null), 0);
+const chosen = /*#__PURE__*/ createVNode(1, "ul", null, xs.map((x)=>x.ok ? /*#__PURE__*/ createVNode(1, "li", null, null, 1, null, x.id) : null), 0);
+const text = /*#__PURE__*/ createVNode(1, "p", null, xs.map((x)=>/*#__PURE__*/ createFragment(x.label, 0)), 0);
+const several = /*#__PURE__*/ createVNode(1, "dl", null, xs.map((x)=>/*#__PURE__*/ createFragment([
+        /*#__PURE__*/ createVNode(1, "dt", null, x.term, 0),
+        /*#__PURE__*/ createVNode(1, "dd", null, x.text, 0)
+    ], 4)), 0);
@@ -0,0 +1,23 @@
+import { createVNode, createComponentVNode, normalizeProps } from "inferno";
+/*#__PURE__*/ normalizeProps(createVNode(1, "div", null, null, 1, {
+    [name]: 'x'
+}));
+/*#__PURE__*/ normalizeProps(createVNode(1, "div", null, null, 1, {
+    id: 'x',
+    ...rest
+}));
+/*#__PURE__*/ normalizeProps(createVNode(1, "div", null, null, 1, {
+    id: 'x',
+    children: c
+}));
+/*#__PURE__*/ normalizeProps(createComponentVNode(2, Com, {
+    get title () {
+        return t;
+    }
+}));
+/*#__PURE__*/ normalizeProps(createVNode(1, "div", null, null, 1, {
+    ...{
+        __proto__: p,
+        id: 1
+    }
+}));
@@ -0,0 +1,20 @@
+import { createVNode, createFragment } from "inferno";
+const a = /*#__PURE__*/ createVNode(1, "div", null, [
+    ...items
+], 0);
+const b = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "span"),
+    ...items
+], 0);
+const c = /*#__PURE__*/ createFragment([
+    ...items
+], 0);
+const d = /*#__PURE__*/ createVNode(1, "div", null, [
+    ...items.map((item)=>cond ? /*#__PURE__*/ createVNode(1, "li") : null)
+], 0);
+const e = /*#__PURE__*/ createVNode(1, "div", null, [
+    ...[
+        ,
+        /*#__PURE__*/ createVNode(1, "li")
+    ]
+], 0);
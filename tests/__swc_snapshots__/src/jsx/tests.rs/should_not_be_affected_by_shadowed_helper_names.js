@@ -0,0 +1,9 @@
+import { createVNode, createTextVNode } from "inferno";
+function App() {
+    const createVNode1 = 1;
+    const createTextVNode1 = 2;
+    return /*#__PURE__*/ createVNode(1, "div", null, [
+        /*#__PURE__*/ createVNode(1, "b"),
+        createTextVNode("text")
+    ], 4);
+}
@@ -0,0 +1,5 @@
+import { createVNode, createComponentVNode } from "inferno";
+import type * as Inferno from 'inferno';
+import { type createComponentVNode as createComponentVNode1, render } from 'inferno';
+const el: Inferno.VNode = /*#__PURE__*/ createVNode(1, "div", null, /*#__PURE__*/ createComponentVNode(2, Child), 2);
+render(el, root);
@@ -0,0 +1,79 @@
+import { createVNode, createComponentVNode } from "inferno";
+function createTemplate(html, slots) {
+    return {
+        html: html,
+        slots: slots,
+        content: null
+    };
+}
+function cloneTemplate(template, values) {
+    return function(dom) {
+        if (dom == null) return;
+        var last = dom.$tv;
+        if (dom.$t !== template) {
+            if (template.content == null) {
+                var el = document.createElement("template");
+                el.innerHTML = template.html;
+                template.content = el.content;
+            }
+            dom.textContent = "";
+            dom.appendChild(template.content.cloneNode(true));
+            var nodes = [];
+            for(var i = 0; i < template.slots.length; i++){
+                var node = dom;
+                for(var j = 0; j < template.slots[i].length - 1; j++){
+                    node = node.childNodes[template.slots[i][j]];
+                }
+                nodes.push(node);
+            }
+            dom.$t = template;
+            dom.$tn = nodes;
+            last = null;
+        }
+        for(var k = 0; k < values.length; k++){
+            var value = values[k];
+            if (last && last[k] === value) continue;
+            var slot = template.slots[k];
+            var name = slot[slot.length - 1];
+            if (name === null) {
+                dom.$tn[k].textContent = value == null || typeof value === "boolean" ? "" : value;
+            } else if (value == null || value === false) {
+                dom.$tn[k].removeAttribute(name);
+            } else {
+                dom.$tn[k].setAttribute(name, value === true ? "" : value);
+            }
+        }
+        dom.$tv = values;
+    };
+}
+const template = createTemplate("<b></b><i></i><u></u>", []);
+const small = /*#__PURE__*/ createVNode(1, "div", null, /*#__PURE__*/ createVNode(1, "span", null, a, 0), 2);
+const component = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "span"),
+    /*#__PURE__*/ createVNode(1, "span"),
+    /*#__PURE__*/ createComponentVNode(2, Item)
+], 4);
+const child = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "span"),
+    /*#__PURE__*/ createVNode(1, "span"),
+    /*#__PURE__*/ createVNode(1, "span", null, item, 0)
+], 4);
+const event = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "span"),
+    /*#__PURE__*/ createVNode(1, "span"),
+    /*#__PURE__*/ createVNode(1, "button", null, null, 1, {
+        onClick: onClick
+    })
+], 4);
+const table = /*#__PURE__*/ createVNode(1, "table", null, /*#__PURE__*/ createVNode(1, "tr", null, [
+    /*#__PURE__*/ createVNode(1, "td"),
+    /*#__PURE__*/ createVNode(1, "td")
+], 4), 2);
+const nested = /*#__PURE__*/ createVNode(1, "main", null, [
+    /*#__PURE__*/ createVNode(1, "div", null, [
+        /*#__PURE__*/ createVNode(1, "span"),
+        /*#__PURE__*/ createVNode(1, "span"),
+        /*#__PURE__*/ createComponentVNode(2, Item)
+    ], 4),
+    /*#__PURE__*/ createVNode(1, "p", null, null, 1, null, "$tb6a6d19b", cloneTemplate(template, []))
+], 4);
@@ -0,0 +1,126 @@
+import { createVNode } from "inferno";
+function createTemplate(html, slots) {
+    return {
+        html: html,
+        slots: slots,
+        content: null
+    };
+}
+function cloneTemplate(template, values) {
+    return function(dom) {
+        if (dom == null) return;
+        var last = dom.$tv;
+        if (dom.$t !== template) {
+            if (template.content == null) {
+                var el = document.createElement("template");
+                el.innerHTML = template.html;
+                template.content = el.content;
+            }
+            dom.textContent = "";
+            dom.appendChild(template.content.cloneNode(true));
+            var nodes = [];
+            for(var i = 0; i < template.slots.length; i++){
+                var node = dom;
+                for(var j = 0; j < template.slots[i].length - 1; j++){
+                    node = node.childNodes[template.slots[i][j]];
+                }
+                nodes.push(node);
+            }
+            dom.$t = template;
+            dom.$tn = nodes;
+            last = null;
+        }
+        for(var k = 0; k < values.length; k++){
+            var value = values[k];
+            if (last && last[k] === value) continue;
+            var slot = template.slots[k];
+            var name = slot[slot.length - 1];
+            if (name === null) {
+                dom.$tn[k].textContent = value == null || typeof value === "boolean" ? "" : value;
+            } else if (value == null || value === false) {
+                dom.$tn[k].removeAttribute(name);
+            } else {
+                dom.$tn[k].setAttribute(name, value === true ? "" : value);
+            }
+        }
+        dom.$tv = values;
+    };
+}
+const template = createTemplate("<ul><li><ul><li>a</li></ul></li></ul><i></i>", [
+    [
+        1,
+        "title"
+    ]
+]);
+const list = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "p", null, /*#__PURE__*/ createVNode(1, "ul", null, /*#__PURE__*/ createVNode(1, "li", null, "a", 16), 2), 2),
+    /*#__PURE__*/ createVNode(1, "i", null, null, 1, {
+        title: q
+    })
+], 4);
+const table = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "p", null, /*#__PURE__*/ createVNode(1, "table"), 2),
+    /*#__PURE__*/ createVNode(1, "i"),
+    /*#__PURE__*/ createVNode(1, "i", null, null, 1, {
+        title: q
+    })
+], 4);
+const section = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "p", null, [
+        /*#__PURE__*/ createVNode(1, "section"),
+        /*#__PURE__*/ createVNode(1, "pre"),
+        /*#__PURE__*/ createVNode(1, "menu")
+    ], 4),
+    /*#__PURE__*/ createVNode(1, "i", null, null, 1, {
+        title: q
+    })
+], 4);
+const items = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "p", null, [
+        /*#__PURE__*/ createVNode(1, "dd"),
+        /*#__PURE__*/ createVNode(1, "dt"),
+        /*#__PURE__*/ createVNode(1, "li")
+    ], 4),
+    /*#__PURE__*/ createVNode(1, "i", null, null, 1, {
+        title: q
+    })
+], 4);
+const button = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "button", null, /*#__PURE__*/ createVNode(1, "b", null, /*#__PURE__*/ createVNode(1, "button"), 2), 2),
+    /*#__PURE__*/ createVNode(1, "i", null, null, 1, {
+        title: q
+    })
+], 4);
+const heading = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "h1", null, /*#__PURE__*/ createVNode(1, "h2", null, "a", 16), 2),
+    /*#__PURE__*/ createVNode(1, "dl", null, /*#__PURE__*/ createVNode(1, "dt", null, "b", 16), 2),
+    /*#__PURE__*/ createVNode(1, "i", null, null, 1, {
+        title: q
+    })
+], 4);
+const terms = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "dl", null, [
+        /*#__PURE__*/ createVNode(1, "dt", null, /*#__PURE__*/ createVNode(1, "dt"), 2),
+        /*#__PURE__*/ createVNode(1, "dd", null, /*#__PURE__*/ createVNode(1, "span", null, /*#__PURE__*/ createVNode(1, "dd"), 2), 2)
+    ], 4),
+    /*#__PURE__*/ createVNode(1, "i", null, null, 1, {
+        title: q
+    })
+], 4);
+const link = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "a", null, /*#__PURE__*/ createVNode(1, "span", null, /*#__PURE__*/ createVNode(1, "a"), 2), 2),
+    /*#__PURE__*/ createVNode(1, "i"),
+    /*#__PURE__*/ createVNode(1, "i", null, null, 1, {
+        title: q
+    })
+], 4);
+const form = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "form", null, /*#__PURE__*/ createVNode(1, "div", null, /*#__PURE__*/ createVNode(1, "form"), 2), 2),
+    /*#__PURE__*/ createVNode(1, "i"),
+    /*#__PURE__*/ createVNode(1, "i", null, null, 1, {
+        title: q
+    })
+], 4);
+const nestedLists = /*#__PURE__*/ createVNode(1, "div", null, null, 1, null, "$te760b485", cloneTemplate(template, [
+    q
+]));
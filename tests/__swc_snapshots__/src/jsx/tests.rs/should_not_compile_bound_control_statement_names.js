@@ -0,0 +1,6 @@
+import { createVNode, createComponentVNode } from "inferno";
+import { If } from './if';
+const a = /*#__PURE__*/ createComponentVNode(2, If, {
+    condition: x,
+    children: /*#__PURE__*/ createVNode(1, "b")
+});
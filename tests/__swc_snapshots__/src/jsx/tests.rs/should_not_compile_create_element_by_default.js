@@ -0,0 +1,2 @@
+import { createElement } from 'inferno-create-element';
+const el = createElement('div', null);
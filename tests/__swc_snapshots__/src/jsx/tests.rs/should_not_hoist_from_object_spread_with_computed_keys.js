@@ -0,0 +1,5 @@
+import { createVNode, normalizeProps } from "inferno";
+/*#__PURE__*/ normalizeProps(createVNode(1, "div", null, null, 1, {
+    key: id,
+    [name]: 'x'
+}));
@@ -0,0 +1,56 @@
+import { createVNode, createComponentVNode, normalizeProps } from "inferno";
+import { Imported } from './imported';
+export const Exported = ({ text })=>/*#__PURE__*/ createVNode(1, "span", null, text, 0);
+/* @inline */ export const Annotated = ({ text })=>/*#__PURE__*/ createVNode(1, "b", null, text, 0);
+const WithDefaults = ({ text })=>/*#__PURE__*/ createVNode(1, "span", null, text, 0);
+WithDefaults.defaultProps = {
+    text: 'a'
+};
+const Twice = ({ text })=>/*#__PURE__*/ createVNode(1, "span", null, text, 0, {
+        title: text
+    });
+const Captured = ({ text })=>/*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+        onClick: ()=>alert(text)
+    });
+const Wrapper = ({ children })=>/*#__PURE__*/ createVNode(1, "div", null, children, 0);
+const Tree = ({ item })=>/*#__PURE__*/ createVNode(1, "li", null, item.children.map((child)=>/*#__PURE__*/ createComponentVNode(2, Tree, {
+            item: child
+        })), 0);
+const Assigned = ({ t })=>/*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+        title: t
+    });
+Object.assign(Assigned, {
+    defaultProps: {
+        t: "d"
+    }
+});
+const app = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createComponentVNode(2, Exported, {
+        text: "a"
+    }),
+    /*#__PURE__*/ createVNode(1, "b", null, "b", 0),
+    /*#__PURE__*/ createComponentVNode(2, WithDefaults),
+    /*#__PURE__*/ createComponentVNode(2, Twice, {
+        text: getText()
+    }),
+    /*#__PURE__*/ createVNode(1, "span", null, name, 0, {
+        title: name
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Captured, {
+        text: name
+    }),
+    /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+        onClick: ()=>alert("c")
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Wrapper, {
+        children: "child"
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Tree, {
+        item: root
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Imported),
+    /*#__PURE__*/ createComponentVNode(2, Assigned),
+    /*#__PURE__*/ normalizeProps(createComponentVNode(2, Exported, {
+        ...props
+    }))
+], 4);
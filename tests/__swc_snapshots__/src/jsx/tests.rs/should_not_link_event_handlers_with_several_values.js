@@ -0,0 +1,60 @@
+import { createVNode, createComponentVNode } from "inferno";
+const a = (items, index)=>items.map((item, i)=>/*#__PURE__*/ createVNode(1, "div", null, [
+            /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+                onClick: ()=>select(item, i)
+            }),
+            /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+                onClick: ()=>select(items[index])
+            }),
+            /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+                onClick: ()=>logout()
+            }),
+            /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+                onClick: ()=>item.a && item.b()
+            }),
+            /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+                onClick: async ()=>await save(item)
+            }),
+            /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+                onClick: (a, b)=>save(item, a, b)
+            }),
+            /*#__PURE__*/ createComponentVNode(2, Item, {
+                onSelect: ()=>select(item)
+            }),
+            /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+                onClick: handler
+            }),
+            /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+                onclick: ()=>select(item)
+            })
+        ], 4));
+function f(item) {
+    item = next(item);
+    for (const other of item.others){
+        use(/*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+            onClick: ()=>select(other, item)
+        }));
+    }
+    return /*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+        onClick: ()=>select(item)
+    });
+}
+function g() {
+    return [
+        1,
+        2
+    ].map((n)=>/*#__PURE__*/ createVNode(1, "span", null, null, 1, {
+            onClick: ()=>select(n, arguments)
+        }));
+}
+for (const x of xs){
+    render(list.map((item)=>/*#__PURE__*/ createVNode(1, "a", null, null, 1, {
+            onClick: ()=>f(x, item)
+        })));
+}
+if (ok) {
+    const y = load();
+    render(list.map((item)=>/*#__PURE__*/ createVNode(1, "a", null, null, 1, {
+            onClick: ()=>f(y, item)
+        })));
+}
@@ -0,0 +1,36 @@
+import { createVNode, createComponentVNode } from "inferno";
+const early = /*#__PURE__*/ createComponentVNode(2, Late);
+const Late = ()=>/*#__PURE__*/ createVNode(1, "div");
+Late.defaultProps = {
+    size: 'm'
+};
+const later = /*#__PURE__*/ createComponentVNode(2, Late, {
+    size: 'm'
+});
+const render = ()=>/*#__PURE__*/ createComponentVNode(2, Late, {
+        size: 'm'
+    });
+const Read = ()=>/*#__PURE__*/ createVNode(1, "div");
+Read.defaultProps = {
+    size: 'm'
+};
+Read.defaultProps.size = 'l';
+const Computed = ()=>/*#__PURE__*/ createVNode(1, "div");
+Computed.defaultProps = {
+    [key]: 'm'
+};
+let Reassigned = ()=>/*#__PURE__*/ createVNode(1, "div");
+Reassigned.defaultProps = {
+    size: 'm'
+};
+const Replaced = ()=>/*#__PURE__*/ createVNode(1, "div");
+Replaced.defaultProps = {
+    size: 'm'
+};
+Replaced = ()=>/*#__PURE__*/ createVNode(1, "span");
+const app = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createComponentVNode(2, Read),
+    /*#__PURE__*/ createComponentVNode(2, Computed),
+    /*#__PURE__*/ createComponentVNode(2, Reassigned),
+    /*#__PURE__*/ createComponentVNode(2, Replaced)
+], 4);
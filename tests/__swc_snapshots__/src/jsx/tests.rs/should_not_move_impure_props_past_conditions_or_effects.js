@@ -0,0 +1,25 @@
+import { createVNode, createComponentVNode } from "inferno";
+const Label = ({ a, b })=>/*#__PURE__*/ createVNode(1, "span", null, a && b, 0);
+const Order = ({ text })=>/*#__PURE__*/ createVNode(1, "span", null, text, 0, {
+        title: log("inner")
+    });
+const Choice = ({ ok, text })=>/*#__PURE__*/ createVNode(1, "span", null, ok ? text : null, 0);
+const Plain = ({ text })=>/*#__PURE__*/ createVNode(1, "span", null, text, 0);
+const app = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createComponentVNode(2, Label, {
+        a: p.ok,
+        b: track()
+    }),
+    /*#__PURE__*/ createVNode(1, "span", null, p.ok && p.text, 0),
+    /*#__PURE__*/ createComponentVNode(2, Order, {
+        text: log("outer")
+    }),
+    /*#__PURE__*/ createVNode(1, "span", null, p.text, 0, {
+        title: log("inner")
+    }),
+    /*#__PURE__*/ createComponentVNode(2, Choice, {
+        ok: p.ok,
+        text: format()
+    }),
+    /*#__PURE__*/ createVNode(1, "span", null, format(), 0)
+], 4);
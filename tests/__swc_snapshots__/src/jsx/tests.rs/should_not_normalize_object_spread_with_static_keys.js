@@ -0,0 +1,11 @@
+import { createVNode, createComponentVNode } from "inferno";
+/*#__PURE__*/ createVNode(1, "div", null, "1", 16, {
+    id: 'x',
+    title,
+    'data-foo': 1,
+    onClick () {}
+});
+/*#__PURE__*/ createComponentVNode(2, Com, {
+    id: 'x',
+    title
+});
@@ -0,0 +1,6 @@
+"use strict";
+const { createVNode, createComponentVNode } = require("inferno");
+const app = /*#__PURE__*/ createComponentVNode(2, App);
+const Inferno = require("inferno");
+const { render } = require("inferno");
+render(/*#__PURE__*/ createVNode(1, "div", null, app, 0), container);
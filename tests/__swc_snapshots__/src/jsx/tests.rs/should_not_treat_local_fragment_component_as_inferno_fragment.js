@@ -0,0 +1,10 @@
+import { createVNode, createComponentVNode } from "inferno";
+function Fragment(props) {
+    return /*#__PURE__*/ createVNode(1, "section", null, props.children, 0);
+}
+/*#__PURE__*/ createComponentVNode(2, Fragment, {
+    children: [
+        /*#__PURE__*/ createVNode(1, "a"),
+        /*#__PURE__*/ createVNode(1, "b")
+    ]
+});
@@ -0,0 +1,6 @@
+import { createComponentVNode } from "inferno";
+import { Portal } from './portal';
+const el = /*#__PURE__*/ createComponentVNode(2, Portal, {
+    container: document.body,
+    children: /*#__PURE__*/ createComponentVNode(2, Modal)
+});
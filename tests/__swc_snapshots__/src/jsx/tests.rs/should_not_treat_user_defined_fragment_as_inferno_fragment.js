@@ -0,0 +1,15 @@
+import { createVNode, createComponentVNode } from "inferno";
+import { Fragment } from './my-fragment';
+import * as Lib from 'some-lib';
+/*#__PURE__*/ createComponentVNode(2, Fragment, {
+    children: [
+        /*#__PURE__*/ createVNode(1, "a"),
+        /*#__PURE__*/ createVNode(1, "b")
+    ]
+});
+/*#__PURE__*/ createComponentVNode(2, Lib.Fragment, {
+    children: [
+        /*#__PURE__*/ createVNode(1, "a"),
+        /*#__PURE__*/ createVNode(1, "b")
+    ]
+});
@@ -0,0 +1,23 @@
+import { createComponentVNode } from "inferno";
+const a = /*#__PURE__*/ createComponentVNode(2, List, {
+    children: [
+        ...items
+    ]
+});
+const b = /*#__PURE__*/ createComponentVNode(2, List, {
+    children: [
+        ...items.map((item)=>/*#__PURE__*/ createComponentVNode(2, Item, null, item.id))
+    ]
+});
+const c = /*#__PURE__*/ createComponentVNode(2, List, {
+    children: [
+        /*#__PURE__*/ createComponentVNode(2, Item),
+        "text"
+    ]
+});
+const d = /*#__PURE__*/ createComponentVNode(2, List, {
+    children: [
+        /*#__PURE__*/ createComponentVNode(2, Item),
+        ...rest
+    ]
+});
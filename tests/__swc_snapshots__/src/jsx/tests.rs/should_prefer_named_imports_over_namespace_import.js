@@ -0,0 +1,3 @@
+import * as Inferno from 'inferno';
+import { createVNode } from 'inferno';
+const el = /*#__PURE__*/ createVNode(1, "div", null, /*#__PURE__*/ Inferno.createComponentVNode(2, Child), 2);
@@ -0,0 +1,21 @@
+import { createVNode, createTextVNode } from "inferno";
+const a = /*#__PURE__*/ createVNode(1, "pre", null, [
+    createTextVNode("\n  function add(a, b) "),
+    createTextVNode('{'),
+    createTextVNode("\n    return a + b;\n  "),
+    createTextVNode('}'),
+    createTextVNode("\n")
+], 4);
+const b = /*#__PURE__*/ createVNode(1, "pre", null, [
+    /*#__PURE__*/ createVNode(1, "code", "js", "\n  const x = 1;\n  const y = 2;\n", 16),
+    createTextVNode("\n")
+], 4);
+const c = /*#__PURE__*/ createVNode(128, "textarea", null, null, 1, {
+    defaultValue: "\n  Dear sir,\n"
+});
+const d = /*#__PURE__*/ createVNode(1, "pre", null, items.map((item)=>/*#__PURE__*/ createVNode(1, "span", null, [
+        createTextVNode("  "),
+        item,
+        createTextVNode("  ")
+    ], 0)), 0);
+const e = /*#__PURE__*/ createVNode(1, "div", null, "collapsed", 16);
@@ -0,0 +1,9 @@
+function ssrMarkup(html) {
+    return {
+        __ssr: html,
+        toString: function() {
+            return html;
+        }
+    };
+}
+const a = /*#__PURE__*/ ssrMarkup("<div><pre>\n\n  indented\n    code</pre><p>  kept  </p></div>");
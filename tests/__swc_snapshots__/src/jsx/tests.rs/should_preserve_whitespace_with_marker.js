@@ -0,0 +1,5 @@
+import { createVNode, createComponentVNode } from "inferno";
+const a = /*#__PURE__*/ createVNode(1, "div", "poem", "\n    Roses are red,\n        violets are blue\n", 16);
+const b = /*#__PURE__*/ createComponentVNode(2, Code, {
+    children: "\n    indented\n"
+});
@@ -0,0 +1,4 @@
+import { render, createVNode, createFragment } from 'inferno';
+render(/*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "div")
+], 4, "a"), container);
@@ -0,0 +1,3 @@
+import { createVNode } from "inferno";
+const a = cond ? null : /*#__PURE__*/ createVNode(1, "div");
+const b = /*#__PURE__*/ createVNode(1, "div", null, "text", 16);
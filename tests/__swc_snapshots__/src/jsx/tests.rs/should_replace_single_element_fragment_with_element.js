@@ -0,0 +1,7 @@
+import { createVNode, createComponentVNode, createTextVNode, createFragment } from "inferno";
+const a = /*#__PURE__*/ createVNode(1, "div");
+const b = /*#__PURE__*/ createComponentVNode(2, Com);
+const c = /*#__PURE__*/ createFragment([
+    createTextVNode("text")
+], 4);
+const d = /*#__PURE__*/ createFragment(x, 0);
@@ -0,0 +1,13 @@
+import * as Inferno from 'inferno';
+/*#__PURE__*/ Inferno.createFragment([
+    /*#__PURE__*/ Inferno.createVNode(1, "a"),
+    /*#__PURE__*/ Inferno.createVNode(1, "b")
+], 4, "a");
+/*#__PURE__*/ Inferno.createFragment([
+    /*#__PURE__*/ Inferno.createVNode(1, "a"),
+    /*#__PURE__*/ Inferno.createVNode(1, "b")
+], 4, "b");
+/*#__PURE__*/ Inferno.createFragment([
+    /*#__PURE__*/ Inferno.createVNode(1, "a"),
+    /*#__PURE__*/ Inferno.createVNode(1, "b")
+], 4, "c");
@@ -0,0 +1,16 @@
+import { createVNode, createFragment } from "inferno";
+import React from 'inferno-compat';
+import * as Compat from 'react';
+import { Fragment as CompatFragment } from 'inferno-compat';
+/*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "a"),
+    /*#__PURE__*/ createVNode(1, "b")
+], 4, "a");
+/*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "a"),
+    /*#__PURE__*/ createVNode(1, "b")
+], 4, "b");
+/*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "a"),
+    /*#__PURE__*/ createVNode(1, "b")
+], 4, "c");
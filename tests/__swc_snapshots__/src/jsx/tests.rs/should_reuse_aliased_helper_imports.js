@@ -0,0 +1,4 @@
+import { createVNode as cv, render, createComponentVNode } from 'inferno';
+function App(createComponentVNode1) {
+    return /*#__PURE__*/ cv(1, "div", null, /*#__PURE__*/ createComponentVNode(2, Child), 2);
+}
@@ -0,0 +1,5 @@
+const _inferno = /*#__PURE__*/ _interop_require_wildcard(require("inferno"));
+export const x = /*#__PURE__*/ _inferno.createVNode(1, "div", null, [
+    /*#__PURE__*/ _inferno.createVNode(1, "span"),
+    /*#__PURE__*/ _inferno.createVNode(1, "b")
+], 4);
@@ -1,2 +1,4 @@
 import { createVNode } from "inferno";
-/*#__PURE__*/ createVNode(128, "textarea", null, "foobar", 16);
+/*#__PURE__*/ createVNode(128, "textarea", null, null, 1, {
+    defaultValue: "foobar"
+});
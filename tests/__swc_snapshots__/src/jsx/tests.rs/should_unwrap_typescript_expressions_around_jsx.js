@@ -0,0 +1,13 @@
+import { createVNode, createComponentVNode, createFragment } from "inferno";
+const a = /*#__PURE__*/ createVNode(1, "div", "a");
+const b = /*#__PURE__*/ createVNode(1, "div", "b");
+const c = /*#__PURE__*/ createVNode(1, "div", "c");
+const d = /*#__PURE__*/ createFragment([
+    /*#__PURE__*/ createVNode(1, "span"),
+    /*#__PURE__*/ createVNode(1, "span")
+], 4);
+const e = /*#__PURE__*/ createVNode(1, "div", null, [
+    /*#__PURE__*/ createVNode(1, "span"),
+    value as string
+], 0);
+render(/*#__PURE__*/ createComponentVNode(2, App), container);
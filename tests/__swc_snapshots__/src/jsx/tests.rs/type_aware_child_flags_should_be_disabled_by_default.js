@@ -0,0 +1,6 @@
+const { createVNode } = require("inferno");
+function Title(props: {
+    title: string;
+}) {
+    return /*#__PURE__*/ createVNode(1, "h1", null, props.title, 0);
+}
@@ -0,0 +1,15 @@
+import * as Inferno from 'inferno';
+import { Store } from './store';
+type Props = {
+    title: string;
+};
+class Header extends Inferno.Component<Props> {
+    render() {
+        return /*#__PURE__*/ Inferno.createVNode(1, "h1", null, this.props.title, 16);
+    }
+}
+class Cached extends Store<Props> {
+    render() {
+        return /*#__PURE__*/ Inferno.createVNode(1, "h1", null, this.props.title, 0);
+    }
+}
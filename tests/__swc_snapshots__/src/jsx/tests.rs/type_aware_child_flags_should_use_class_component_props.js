@@ -0,0 +1,15 @@
+import { Component, createVNode } from 'inferno';
+type Props = {
+    title: string;
+    label: 'a' | 'b';
+};
+class Header extends Component<Props> {
+    render() {
+        const { label } = this.props;
+        const heading: JSX.Element = /*#__PURE__*/ createVNode(1, "h2", null, label, 16);
+        return /*#__PURE__*/ createVNode(1, "header", null, [
+            /*#__PURE__*/ createVNode(1, "h1", null, this.props.title, 16),
+            heading
+        ], 4);
+    }
+}
@@ -0,0 +1,18 @@
+import { VNode, createVNode, createTextVNode } from 'inferno';
+interface Props {
+    title: string;
+    count: number;
+    icon: VNode;
+    subtitle?: string;
+}
+function Title(props: Props) {
+    return /*#__PURE__*/ createVNode(1, "h1", null, props.title, 16);
+}
+const Count = ({ count, icon, subtitle }: Props)=>/*#__PURE__*/ createVNode(1, "div", null, [
+        /*#__PURE__*/ createVNode(1, "span", null, [
+            createTextVNode(count),
+            createTextVNode(' items')
+        ], 4),
+        /*#__PURE__*/ createVNode(1, "i", null, icon, 2),
+        /*#__PURE__*/ createVNode(1, "small", null, subtitle, 0)
+    ], 4);
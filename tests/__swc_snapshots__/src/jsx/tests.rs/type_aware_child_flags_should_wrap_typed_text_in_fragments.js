@@ -0,0 +1,6 @@
+const { createVNode, createTextVNode, createFragment } = require("inferno");
+const name: string = getName();
+const a = /*#__PURE__*/ createFragment([
+    createTextVNode(name),
+    /*#__PURE__*/ createVNode(1, "b")
+], 4);
@@ -1,3 +1,2 @@
 const { createVNode } = require("inferno");
-const { createVNode: createVNode1 } = require("inferno");
 var x = /*#__PURE__*/ createVNode(1, "div", null, "Hello", 16);
@@ -0,0 +1 @@
+const tag = <div $Tag />;
@@ -0,0 +1,2 @@
+import { createVNode } from "inferno";
+const tag = /*#__PURE__*/ createVNode(1, "div");
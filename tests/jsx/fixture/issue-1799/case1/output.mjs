@@ -1,7 +1,7 @@
 // Foo.jsx
-import Inferno, { createVNode } from "inferno";
+import Inferno from "inferno";
 export default function Foo() {
-    return /*#__PURE__*/ createVNode(1, "div", null, null, 1, {
+    return /*#__PURE__*/ Inferno.createVNode(1, "div", null, null, 1, {
         onClick: async (e)=>{
             await doSomething();
         }
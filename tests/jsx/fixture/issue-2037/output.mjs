@@ -1,4 +1,4 @@
 import { createVNode } from "inferno";
 const A = ()=>{
-    return /*#__PURE__*/ createVNode(1, "div", null, [], 2);
+    return /*#__PURE__*/ createVNode(1, "div");
 };
@@ -1,6 +1,4 @@
-import { createVNode, createFragment } from "inferno";
+import { createVNode } from "inferno";
 export var App = function() {
-    return /*#__PURE__*/ createFragment([
-        /*#__PURE__*/ createVNode(1, "div", null, "1", 16)
-    ], 4);
+    return /*#__PURE__*/ createVNode(1, "div", null, "1", 16);
 };
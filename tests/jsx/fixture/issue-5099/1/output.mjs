@@ -1,4 +1,4 @@
-import { createVNode, createFragment } from "inferno";
+import { createVNode } from "inferno";
 /** @jsx h */ /** @jsxFrag */ import { h } from "preact";
 import { Marked } from "markdown";
 export const handler = {
@@ -12,11 +12,9 @@ export const handler = {
     }
 };
 export default function Greet(props) {
-    return /*#__PURE__*/ createFragment([
-        /*#__PURE__*/ createVNode(1, "div", null, null, 1, {
-            dangerouslySetInnerHTML: {
-                __html: props.data.markup.content
-            }
-        })
-    ], 4);
+    return /*#__PURE__*/ createVNode(1, "div", null, null, 1, {
+        dangerouslySetInnerHTML: {
+            __html: props.data.markup.content
+        }
+    });
 }
@@ -1,2 +1 @@
-import { createFragment } from "inferno";
-/** @jsxRuntime typo */ /*#__PURE__*/ createFragment();
+/** @jsxRuntime typo */ null;
@@ -1,2 +1 @@
-import { createFragment } from "inferno";
-/** @jsxRuntime */ /** @jsxImportSource */ /** @jsxFrag */ /** @jsx */ /*#__PURE__*/ createFragment();
+/** @jsxRuntime */ /** @jsxImportSource */ /** @jsxFrag */ /** @jsx */ null;
@@ -1,17 +1,17 @@
-import { createVNode, normalizeProps } from "inferno";
+import { createVNode } from "inferno";
 /*#__PURE__*/ createVNode(1, "p", null, "text", 16, {
     prop: true,
     prop: true
 });
-/*#__PURE__*/ normalizeProps(createVNode(1, "p", null, "text", 16, {
+/*#__PURE__*/ createVNode(1, "p", null, "text", 16, {
     prop,
     prop
-}));
-/*#__PURE__*/ normalizeProps(createVNode(1, "p", null, "text", 16, {
+});
+/*#__PURE__*/ createVNode(1, "p", null, "text", 16, {
     prop: true,
     prop
-}));
-/*#__PURE__*/ normalizeProps(createVNode(1, "p", null, "text", 16, {
+});
+/*#__PURE__*/ createVNode(1, "p", null, "text", 16, {
     prop,
     prop: true
-}));
+});
@@ -1,3 +1,2 @@
-import { createComponentVNode } from "inferno";
 import * as React from "inferno";
-var x = /*#__PURE__*/ createComponentVNode(2, Inferno.Fragment, null, "foo");
+var x = /*#__PURE__*/ React.createFragment(null, 1, "foo");
@@ -1,2 +1 @@
-import { createFragment } from "inferno";
-var x = /*#__PURE__*/ createFragment();
+var x = null;
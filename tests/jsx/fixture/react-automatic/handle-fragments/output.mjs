@@ -1,4 +1,2 @@
-import { createVNode, createFragment } from "inferno";
-var x = /*#__PURE__*/ createFragment([
-    /*#__PURE__*/ createVNode(1, "div")
-], 4);
+import { createVNode } from "inferno";
+var x = /*#__PURE__*/ createVNode(1, "div");
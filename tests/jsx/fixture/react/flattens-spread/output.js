@@ -5,11 +5,11 @@ import { createVNode, normalizeProps } from "inferno";
 /*#__PURE__*/ normalizeProps(createVNode(1, "div", null, contents, 0, {
     ...props
 }));
-/*#__PURE__*/ normalizeProps(createVNode(1, "img", null, null, 1, {
+/*#__PURE__*/ createVNode(1, "img", null, null, 1, {
     alt: "",
     src,
     title
-}));
-/*#__PURE__*/ normalizeProps(createVNode(1, "blockquote", null, items, 0, {
+});
+/*#__PURE__*/ createVNode(1, "blockquote", null, items, 0, {
     cite
-}));
+});
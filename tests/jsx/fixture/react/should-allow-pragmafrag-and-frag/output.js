@@ -1,2 +1 @@
-import { createFragment } from "inferno";
-/** @jsx dom */ /** @jsxFrag DomFrag */ /*#__PURE__*/ createFragment();
+/** @jsx dom */ /** @jsxFrag DomFrag */ null;
@@ -1,2 +1,4 @@
 import { createVNode } from "inferno";
-/*#__PURE__*/ createVNode(1, "div", null, children, 2);
+/*#__PURE__*/ createVNode(1, "div", null, [
+    ...children
+], 0);
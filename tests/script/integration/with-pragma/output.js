@@ -1,7 +1,5 @@
-const { createVNode, createFragment } = require("inferno");
+const { createVNode } = require("inferno");
 /**@jsxRuntime automatic */ const App = /*#__PURE__*/ createVNode(1, "div", null, [
     /*#__PURE__*/ createVNode(1, "div"),
-    /*#__PURE__*/ createFragment([
-        /*#__PURE__*/ createVNode(1, "div", null, "hoge", 16)
-    ], 4)
+    /*#__PURE__*/ createVNode(1, "div", null, "hoge", 16)
 ], 4);